@@ -0,0 +1,249 @@
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use bevy::prelude::*;
+use bevy_girk_host_server::LobbiesCache;
+use game_core::validate_player_name;
+use wiring_backend::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Lobbies are taken out of the host server's lobby cache while their launch is pending, so tracked lobbies are
+/// only dropped after they have been missing for longer than a launch can take.
+const MISSING_LOBBY_TIMEOUT: Duration = Duration::from_millis(ACK_TIMEOUT_MILLIS * 2);
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Sends a lobby's member states to its members.
+fn broadcast_member_states(server: &LobbyControlServer, control: &LobbyControl, lobby_id: u64)
+{
+    let Some(states) = control.member_states(lobby_id) else { return };
+    for member_id in control.members(lobby_id) {
+        server.send(member_id, LobbyControlToUserMsg::MemberStates { lobby_id, states: states.clone() });
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Syncs a lobby with the host server's copy, and sends the member states to its members if the members changed.
+///
+/// Returns `false` if the host server doesn't have the lobby.
+fn sync_lobby(server: &LobbyControlServer, lobbies: &LobbiesCache, control: &LobbyControl, lobby_id: u64) -> bool
+{
+    let Some(lobby) = lobbies.lobby_ref(lobby_id) else { return false };
+    if control.sync_lobby(lobby) {
+        broadcast_member_states(server, control, lobby_id);
+    }
+    true
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Removes a member from a lobby.
+///
+/// The member is told to leave the lobby through the host server. Until they leave, they can't change their member
+/// state and the lobby can't launch.
+fn kick_member(server: &LobbyControlServer, control: &LobbyControl, lobby_id: u64, member_id: u128)
+{
+    if !control.kick_member(lobby_id, member_id) {
+        return;
+    }
+    tracing::info!(lobby_id, member_id, "kicking lobby member");
+    server.send(member_id, LobbyControlToUserMsg::Kicked { lobby_id });
+    broadcast_member_states(server, control, lobby_id);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn handle_lobby_control_msg(
+    server: &LobbyControlServer,
+    lobbies: &LobbiesCache,
+    control: &LobbyControl,
+    ratings: &PlayerRatingsStore,
+    user_id: u128,
+    msg: UserToLobbyControlMsg,
+)
+{
+    // sync with the host server first so only current members can change member states
+    if let Some(lobby_id) = msg.lobby_id() {
+        if !sync_lobby(server, lobbies, control, lobby_id) {
+            tracing::debug!(lobby_id, user_id, "ignoring lobby control message for unknown lobby");
+            return;
+        }
+    }
+
+    match msg {
+        UserToLobbyControlMsg::GetMemberStates { lobby_id } => {
+            if !control.has_member(lobby_id, user_id) {
+                tracing::debug!(lobby_id, user_id, "ignoring member states request from non-member");
+                return;
+            }
+            let Some(states) = control.member_states(lobby_id) else { return };
+            server.send(user_id, LobbyControlToUserMsg::MemberStates { lobby_id, states });
+        }
+        UserToLobbyControlMsg::Kick { lobby_id, member_id } => {
+            if control.owner(lobby_id) != Some(user_id) || member_id == user_id {
+                tracing::debug!(lobby_id, user_id, member_id, "ignoring invalid kick request");
+                return;
+            }
+            if !control.has_member(lobby_id, member_id) {
+                tracing::debug!(lobby_id, member_id, "ignoring kick request for non-member");
+                return;
+            }
+            kick_member(server, control, lobby_id, member_id);
+        }
+        UserToLobbyControlMsg::SetBanned { lobby_id, user_id: target_id, banned } => {
+            if control.owner(lobby_id) != Some(user_id) {
+                tracing::debug!(lobby_id, user_id, target_id, "ignoring ban request from non-owner");
+                return;
+            }
+            if !control.set_banned(lobby_id, target_id, banned) {
+                tracing::debug!(lobby_id, target_id, banned, "failed updating lobby ban");
+                return;
+            }
+            tracing::info!(lobby_id, target_id, banned, "updated lobby ban");
+
+            // banned members are kicked
+            // - kick after broadcasting so the kicked member sees the ban
+            let kick = banned && control.has_member(lobby_id, target_id);
+            broadcast_member_states(server, control, lobby_id);
+            if kick {
+                kick_member(server, control, lobby_id, target_id);
+            }
+        }
+        UserToLobbyControlMsg::SetReady { lobby_id, ready } => {
+            if !control.set_ready(lobby_id, user_id, ready) {
+                tracing::debug!(lobby_id, user_id, ready, "ignoring ready request from non-member");
+                return;
+            }
+            tracing::trace!(lobby_id, user_id, ready, "updated lobby member ready");
+            broadcast_member_states(server, control, lobby_id);
+        }
+        UserToLobbyControlMsg::SetHandicap { lobby_id, member_id, handicap } => {
+            if control.owner(lobby_id) != Some(user_id) {
+                tracing::debug!(lobby_id, user_id, member_id, "ignoring handicap request from non-owner");
+                return;
+            }
+            if !ClickLobbyChecker::valid_handicap(&handicap) {
+                tracing::debug!(lobby_id, member_id, ?handicap, "ignoring invalid handicap");
+                return;
+            }
+            if !control.set_handicap(lobby_id, member_id, handicap) {
+                tracing::debug!(lobby_id, member_id, "ignoring handicap request for non-member");
+                return;
+            }
+            tracing::info!(lobby_id, member_id, ?handicap, "updated lobby member handicap");
+            broadcast_member_states(server, control, lobby_id);
+        }
+        UserToLobbyControlMsg::SetName { lobby_id, name } => {
+            if let Some(Err(err)) = name.as_ref().map(|name| validate_player_name(name)) {
+                tracing::debug!(lobby_id, user_id, ?err, "ignoring invalid lobby member name");
+                return;
+            }
+            if !control.set_name(lobby_id, user_id, name) {
+                tracing::debug!(lobby_id, user_id, "ignoring name request from non-member or for a taken name");
+                return;
+            }
+            tracing::trace!(lobby_id, user_id, "updated lobby member name");
+            broadcast_member_states(server, control, lobby_id);
+        }
+        UserToLobbyControlMsg::GetRatings { user_ids } => {
+            if user_ids.len() > MAX_RATINGS_PER_REQUEST {
+                tracing::debug!(user_id, num = user_ids.len(), "ignoring oversized ratings request");
+                return;
+            }
+            let ratings = user_ids
+                .into_iter()
+                .map(|id| (id, ratings.get(id)))
+                .collect();
+            server.send(user_id, LobbyControlToUserMsg::Ratings { ratings });
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn handle_lobby_control_incoming(
+    server: Res<LobbyControlServer>,
+    lobbies: Res<LobbiesCache>,
+    control: Res<LobbyControl>,
+    ratings: Res<PlayerRatingsStore>,
+)
+{
+    while let Some((user_id, event)) = server.next() {
+        match event {
+            LobbyControlServerEvent::Report(report) => tracing::trace!(user_id, ?report, "lobby control report"),
+            LobbyControlServerEvent::Msg(msg) => {
+                handle_lobby_control_msg(&server, &lobbies, &control, &ratings, user_id, msg);
+            }
+            LobbyControlServerEvent::Request(token, _) => {
+                tracing::debug!(user_id, "rejecting unexpected lobby control request");
+                let _ = server.reject(token);
+            }
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Syncs tracked lobbies with the host server, so members who leave are forgotten and dropped lobbies are removed.
+fn sync_lobbies(
+    server: Res<LobbyControlServer>,
+    lobbies: Res<LobbiesCache>,
+    control: Res<LobbyControl>,
+    mut missing_since: Local<HashMap<u64, Instant>>,
+)
+{
+    let now = Instant::now();
+    let lobby_ids = control.lobby_ids();
+    missing_since.retain(|lobby_id, _| lobby_ids.contains(lobby_id));
+
+    for lobby_id in lobby_ids {
+        if sync_lobby(&server, &lobbies, &control, lobby_id) {
+            missing_since.remove(&lobby_id);
+            continue;
+        }
+
+        let since = *missing_since.entry(lobby_id).or_insert(now);
+        if now.duration_since(since) < MISSING_LOBBY_TIMEOUT {
+            continue;
+        }
+        tracing::debug!(lobby_id, "removing lobby dropped by the host server");
+        control.remove_lobby(lobby_id);
+        missing_since.remove(&lobby_id);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Sends players their new rating after a rated game ends.
+fn send_updated_ratings(server: Res<LobbyControlServer>, ratings: Res<PlayerRatingsStore>)
+{
+    for user_id in ratings.take_updated() {
+        let rating = ratings.get(user_id);
+        server.send(user_id, LobbyControlToUserMsg::Ratings { ratings: vec![(user_id, rating)] });
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Runs the lobby control server alongside the host server.
+///
+/// The app must be a host server app.
+pub(crate) fn add_lobby_control(
+    app: &mut App,
+    server: LobbyControlServer,
+    control: LobbyControl,
+    ratings: PlayerRatingsStore,
+)
+{
+    app.insert_resource(server)
+        .insert_resource(control)
+        .insert_resource(ratings)
+        .add_systems(
+            Update,
+            (sync_lobbies, handle_lobby_control_incoming, send_updated_ratings).chain(),
+        );
+}
+
+//-------------------------------------------------------------------------------------------------------------------
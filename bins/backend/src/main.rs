@@ -1,3 +1,6 @@
+mod lobby_control;
+mod rated_game_launcher;
+
 use std::net::{IpAddr, Ipv6Addr};
 use std::path::PathBuf;
 use std::sync::Arc;
@@ -13,13 +16,15 @@ use bevy_girk_utils::*;
 use clap::Parser;
 use enfync::AdoptOrDefault;
 use game_core::*;
+use lobby_control::*;
+use rated_game_launcher::*;
 use renet2_setup::GameServerSetupConfig;
 use wiring_backend::*;
 use wiring_game_instance::*;
 
 //-------------------------------------------------------------------------------------------------------------------
 
-fn make_host_server_configs() -> HostServerStartupPack
+fn make_host_server_configs(control: LobbyControl) -> HostServerStartupPack
 {
     // configs
     let host_server_config = HostServerConfig {
@@ -32,6 +37,7 @@ fn make_host_server_configs() -> HostServerStartupPack
             max_lobby_players: MAX_LOBBY_PLAYERS,
             max_lobby_watchers: MAX_LOBBY_WATCHERS,
             min_players_to_launch: MIN_PLAYERS_TO_LAUNCH,
+            control,
         }),
     };
     let pending_lobbies_cache_config = PendingLobbiesConfig {
@@ -76,7 +82,6 @@ fn make_click_game_configs(
     local_ip: Option<IpAddr>,
     proxy_ip: Option<IpAddr>,
     game_ticks_per_sec: u32,
-    game_num_ticks: u32,
     ws_domain: Option<String>,
     wss_certs: Option<(PathBuf, PathBuf)>,
 ) -> ClickGameFactoryConfig
@@ -87,7 +92,6 @@ fn make_click_game_configs(
 
     // config
     let max_init_ticks = game_ticks_per_sec * 5;
-    let game_prep_ticks = 0;
     let max_game_over_ticks = game_ticks_per_sec * 3;
 
     // server setup config
@@ -112,42 +116,94 @@ fn make_click_game_configs(
     let game_fw_config = GameFwConfig::new(game_ticks_per_sec, max_init_ticks, max_game_over_ticks);
 
     // game duration config
-    let duration_config = GameDurationConfig::new(game_prep_ticks, game_num_ticks);
+    // - prep and play durations are replaced by the lobby's game settings when a game launches
+    // - tied games go to sudden-death overtime for up to 10 seconds
+    let duration_config = GameDurationConfig::new(
+        DEFAULT_PREP_SECS as u32 * game_ticks_per_sec,
+        DEFAULT_GAME_SECS as u32 * game_ticks_per_sec,
+    )
+    .with_overtime(game_ticks_per_sec * 10);
+
+    // click limit config
+    // - at most 15 clicks per second
+    // - flag players whose last 20 click intervals are nearly identical
+    let click_limit_config = ClickLimitConfig::new(game_ticks_per_sec, 15, 20, 0.05);
+
+    // pause config
+    // - pauses need a majority vote
+    // - each player can start 2 pauses, which resume automatically after 30 seconds
+    let pause_config = PauseConfig::new(PauseMode::Vote, 2, game_ticks_per_sec * 30);
+
+    // chat config
+    // - messages up to 200 characters
+    // - at most 5 messages every 10 seconds
+    // - watchers can only chat with other watchers
+    let chat_config = ChatConfig::new(200, game_ticks_per_sec * 10, 5, false);
+
+    // disconnect config
+    // - disconnected players forfeit if they don't reconnect within 30 seconds
+    let disconnect_config = DisconnectConfig::new(game_ticks_per_sec * 30);
 
     // click game factory config
     ClickGameFactoryConfig {
         server_setup_config,
         game_fw_config,
         duration_config,
+        game_mode: GameMode::Timed,
+        click_limit_config,
+        pause_config,
+        chat_config,
+        disconnect_config,
+        replay_dir: None,
         resend_time: Duration::from_millis(300),
     }
 }
 
 //-------------------------------------------------------------------------------------------------------------------
 
+fn make_acceptor(rustls_config: Option<Arc<rustls::ServerConfig>>) -> bevy_simplenet::AcceptorConfig
+{
+    match rustls_config {
+        Some(rustls_config) => bevy_simplenet::AcceptorConfig::Rustls(
+            axum_server::tls_rustls::RustlsConfig::from_config(rustls_config),
+        ),
+        None => bevy_simplenet::AcceptorConfig::Default,
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 fn make_test_host_server(
     host_addr: String,
+    control_addr: String,
     rustls_config: Option<Arc<rustls::ServerConfig>>,
     configs: HostServerStartupPack,
-) -> (App, url::Url, url::Url)
+    control: LobbyControl,
+    ratings: PlayerRatingsStore,
+) -> (App, url::Url, url::Url, url::Url)
 {
     // host-user server
     // - Make this first so host-hub server's wildcard port doesn't steal the server's pre-specified port.
-    let acceptor = match rustls_config {
-        Some(rustls_config) => bevy_simplenet::AcceptorConfig::Rustls(
-            axum_server::tls_rustls::RustlsConfig::from_config(rustls_config),
-        ),
-        None => bevy_simplenet::AcceptorConfig::Default,
-    };
     let host_user_server = host_user_server_factory().new_server(
         enfync::builtin::native::TokioHandle::adopt_or_default(),
         host_addr,
-        acceptor,
+        make_acceptor(rustls_config.clone()),
         bevy_simplenet::Authenticator::None,
         bevy_simplenet::ServerConfig::default(),
     );
     let host_user_url = host_user_server.url();
 
+    // lobby control server
+    // - users authenticate the same way as for the host-user server
+    let lobby_control_server = lobby_control_server_factory().new_server(
+        enfync::builtin::native::TokioHandle::adopt_or_default(),
+        control_addr,
+        make_acceptor(rustls_config),
+        bevy_simplenet::Authenticator::None,
+        bevy_simplenet::ServerConfig::default(),
+    );
+    let lobby_control_url = lobby_control_server.url();
+
     // host-hub server
     let host_hub_server = host_hub_server_factory().new_server(
         enfync::builtin::native::TokioHandle::adopt_or_default(),
@@ -158,11 +214,10 @@ fn make_test_host_server(
     );
     let host_hub_url = host_hub_server.url();
 
-    (
-        make_host_server(configs, host_hub_server, host_user_server),
-        host_hub_url,
-        host_user_url,
-    )
+    let mut host_server = make_host_server(configs, host_hub_server, host_user_server);
+    add_lobby_control(&mut host_server, lobby_control_server, control, ratings);
+
+    (host_server, host_hub_url, host_user_url, lobby_control_url)
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -185,15 +240,20 @@ fn make_test_game_hub_server(
     hub_server_url: url::Url,
     startup_pack: GameHubServerStartupPack,
     game_factory_config: ClickGameFactoryConfig,
+    control: LobbyControl,
+    ratings: PlayerRatingsStore,
 ) -> (Sender<GameHubCommand>, App)
 {
     // setup
     let (command_sender, command_receiver) = new_channel::<GameHubCommand>();
     let host_hub_client = make_test_host_hub_client_with_id(0u128, hub_server_url);
-    let game_launch_pack_source = GameLaunchPackSource::new(ClickGameLaunchPackSource::new(game_factory_config));
-    let game_launcher = GameInstanceLauncher::new(GameInstanceLauncherProcess::new(
-        game_instance_path,
-        enfync::builtin::native::TokioHandle::adopt_or_default(),
+    let game_launch_pack_source =
+        GameLaunchPackSource::new(ClickGameLaunchPackSource::new(game_factory_config, control, ratings.clone()));
+    let spawner = enfync::builtin::native::TokioHandle::adopt_or_default();
+    let game_launcher = GameInstanceLauncher::new(RatedGameInstanceLauncher::new(
+        GameInstanceLauncherProcess::new(game_instance_path, spawner.clone()),
+        ratings,
+        spawner,
     ));
 
     // server app
@@ -221,6 +281,9 @@ struct BackendCli
     /// Address of user-host server.
     #[arg(long)]
     host_addr: Option<String>,
+    /// Address of the lobby control server.
+    #[arg(long)]
+    control_addr: Option<String>,
     /// Local IP for game servers.
     #[arg(long)]
     local_ip: Option<IpAddr>,
@@ -238,6 +301,9 @@ struct BackendCli
     /// Privkey for websocket certs, should be `PEM` encoded.
     #[arg(long)]
     wss_certs_privkey: Option<String>,
+    /// File where player ratings are stored. Ratings are only kept in memory if not specified.
+    #[arg(long)]
+    ratings_file: Option<PathBuf>,
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -278,6 +344,7 @@ fn main()
         .game_instance
         .unwrap_or_else(|| String::from(GAME_INSTANCE_PATH));
     let host_addr = args.host_addr.unwrap_or_else(|| "127.0.0.1:48888".into());
+    let control_addr = args.control_addr.unwrap_or_else(|| "127.0.0.1:48889".into());
 
     let wss_certs = match (args.wss_certs, args.wss_certs_privkey) {
         (Some(certs), Some(privkey)) => Some((PathBuf::from(certs), PathBuf::from(privkey))),
@@ -298,13 +365,25 @@ fn main()
     };
 
     // launch host server
-    let (mut host_server, host_hub_url, host_user_url) =
-        make_test_host_server(host_addr, maybe_rustls, make_host_server_configs());
+    // - live lobby state and player ratings are shared between the host server and the game hub server
+    let control = LobbyControl::default();
+    let ratings = match PlayerRatingsStore::load(args.ratings_file.clone()) {
+        Ok(ratings) => ratings,
+        Err(err) => panic!("failed loading player ratings: {err}"),
+    };
+    let (mut host_server, host_hub_url, host_user_url, lobby_control_url) = make_test_host_server(
+        host_addr,
+        control_addr,
+        maybe_rustls,
+        make_host_server_configs(control.clone()),
+        control.clone(),
+        ratings.clone(),
+    );
     tracing::info!("host-user server running at {}", host_user_url.as_str());
+    tracing::info!("lobby control server running at {}", lobby_control_url.as_str());
 
     // launch game hub server attached to host server
     let game_ticks_per_sec = 20;
-    let game_num_ticks = 20 * 30;
 
     // run the servers
     std::thread::spawn(move || {
@@ -316,10 +395,11 @@ fn main()
                 args.local_ip,
                 args.proxy_ip,
                 game_ticks_per_sec,
-                game_num_ticks,
                 args.ws_domain.clone(),
                 wss_certs.clone(),
             ),
+            control,
+            ratings,
         );
         hub_server.run()
     });
@@ -0,0 +1,79 @@
+use bevy_girk_game_fw::GameOverReport;
+use bevy_girk_game_instance::*;
+use bevy_girk_utils::*;
+use enfync::Handle;
+use game_core::ClickGameOverReport;
+use wiring_backend::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Rates a game from its game over report.
+fn rate_game(ratings: &PlayerRatingsStore, game_id: u64, report: &GameOverReport)
+{
+    let Some(report) = report.get::<ClickGameOverReport>() else {
+        tracing::error!(game_id, "failed deserializing game over report for rating the game");
+        ratings.remove_game(game_id);
+        return;
+    };
+    ratings.apply_game_over(game_id, &report);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Launches game instance processes, and updates player ratings when their games end.
+///
+/// Game instances only report results, so ratings are updated by the backend alone.
+#[derive(Debug)]
+pub(crate) struct RatedGameInstanceLauncher
+{
+    launcher: GameInstanceLauncherProcess,
+    ratings: PlayerRatingsStore,
+    spawner: enfync::builtin::native::TokioHandle,
+}
+
+impl RatedGameInstanceLauncher
+{
+    pub(crate) fn new(
+        launcher: GameInstanceLauncherProcess,
+        ratings: PlayerRatingsStore,
+        spawner: enfync::builtin::native::TokioHandle,
+    ) -> Self
+    {
+        Self { launcher, ratings, spawner }
+    }
+}
+
+impl GameInstanceLauncherImpl for RatedGameInstanceLauncher
+{
+    fn launch(&self, launch_pack: GameLaunchPack, report_sender: IoSender<GameInstanceReport>) -> GameInstance
+    {
+        // intercept reports from the game instance
+        let (instance_report_sender, mut instance_report_receiver) = new_io_channel::<GameInstanceReport>();
+        let instance = self.launcher.launch(launch_pack, instance_report_sender);
+
+        let ratings = self.ratings.clone();
+        self.spawner.spawn(async move {
+            let mut game_id = None;
+            while let Some(report) = instance_report_receiver.recv().await {
+                match &report {
+                    GameInstanceReport::GameStart(id, _) => game_id = Some(*id),
+                    GameInstanceReport::GameOver(id, game_over_report) => {
+                        game_id = Some(*id);
+                        rate_game(&ratings, *id, game_over_report);
+                    }
+                    _ => (),
+                }
+                let _ = report_sender.send(report);
+            }
+
+            // games that end without a game over report are unrated
+            if let Some(game_id) = game_id {
+                ratings.remove_game(game_id);
+            }
+        });
+
+        instance
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -1,3 +1,4 @@
+use std::path::PathBuf;
 use std::str::FromStr;
 use std::time::Duration;
 
@@ -15,8 +16,7 @@ use wiring_game_instance::ClickGameFactory;
 
 //-------------------------------------------------------------------------------------------------------------------
 
-//todo: specify app data file path (e.g. contains auth keys [temp solution before 'login'-style auth], logs,
-// settings)
+//todo: specify app data file path (e.g. contains auth keys [temp solution before 'login'-style auth], logs)
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
 struct ClientCli
@@ -27,9 +27,16 @@ struct ClientCli
     /// Alt: GIRK_HOST_ADDR env variable (required for WASM clients)
     #[arg(long = "addr")]
     server_addr: Option<String>,
+    /// Address of the lobby control server.
+    /// Alt: GIRK_CONTROL_ADDR env variable (required for WASM clients)
+    #[arg(long = "control-addr")]
+    control_addr: Option<String>,
     /// Alt: GIRK_HOST_IS_WSS env variable (required for WASM clients)
     #[arg(long)]
     host_is_wss: Option<bool>,
+    /// Path to the user settings file (default: `user_settings.json`). Settings are not saved in WASM clients.
+    #[arg(long = "settings")]
+    settings_path: Option<PathBuf>,
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -87,10 +94,18 @@ fn main()
         .server_addr
         .or_else(|| std::option_env!("GIRK_HOST_ADDR").map(|s| s.into()))
         .unwrap_or_else(|| "127.0.0.1:48888".into());
+    let control_addr = args
+        .control_addr
+        .or_else(|| std::option_env!("GIRK_CONTROL_ADDR").map(|s| s.into()))
+        .unwrap_or_else(|| "127.0.0.1:48889".into());
     let host_is_wss = args
         .host_is_wss
         .or_else(|| std::option_env!("GIRK_HOST_IS_WSS").map(|s| bool::from_str(s).unwrap_or_default()))
         .unwrap_or_default();
+    #[cfg(not(target_family = "wasm"))]
+    let settings_path = Some(args.settings_path.unwrap_or_else(|| "user_settings.json".into()));
+    #[cfg(target_family = "wasm")]
+    let settings_path = None;
 
     #[cfg(not(target_family = "wasm"))]
     {
@@ -112,6 +127,8 @@ fn main()
     let host = if host_is_wss { "wss" } else { "ws" };
     let url = format!("{host}://{}/ws", server_addr.as_str());
     tracing::info!("connecting to host server: {}", url.as_str());
+    let control_url = format!("{host}://{}/ws", control_addr.as_str());
+    tracing::info!("connecting to lobby control server: {}", control_url.as_str());
 
     // prep to launch client
     // - todo: receive URL from HTTP(s) server, and load the HTTP(s) URL from an asset
@@ -128,6 +145,16 @@ fn main()
         )
     };
 
+    let make_control_client = move || {
+        lobby_control_client_factory().new_client(
+            enfync::builtin::Handle::default(),
+            url::Url::parse(control_url.as_str()).unwrap(),
+            bevy_simplenet::AuthRequest::None { client_id },
+            bevy_simplenet::ClientConfig::default(),
+            (),
+        )
+    };
+
     // timer configs for the user client (TEMPORARY: use asset instead ?)
     let timer_configs = TimerConfigs {
         host_reconstruct_loop_ms: 500,
@@ -147,7 +174,9 @@ fn main()
     App::new()
         .add_plugins(ClientInstancePlugin::new(factory, Some(game_factory)))
         .insert_resource(HostClientConstructor::new(make_client))
+        .insert_resource(LobbyControlClientConstructor::new(make_control_client))
         .insert_resource(timer_configs)
+        .insert_resource(UserSettingsPath(settings_path))
         .add_plugins(ClickUserClientPlugin)
         .run();
 }
@@ -1,6 +1,9 @@
 //! Independent client binary. Can be used to launch games directly from another binary without an intermediating
 //! user client.
 
+mod replay_viewer;
+
+use std::path::PathBuf;
 use std::time::Duration;
 
 use bevy::prelude::*;
@@ -9,6 +12,8 @@ use bevy_girk_client_instance::*;
 use bevy_girk_game_instance::GameStartInfo;
 use bevy_girk_utils::*;
 use clap::Parser;
+use game_core::ClickGameReplay;
+use replay_viewer::*;
 use renet2_setup::ServerConnectToken;
 use wiring_client_instance::*;
 
@@ -23,6 +28,9 @@ struct GameClientCli
     /// GameStartInfo
     #[arg(short = 'S', value_parser = parse_json::<GameStartInfo>)]
     start_info: Option<GameStartInfo>,
+    /// Replay file to play back as a local game. Other args are ignored if this is set.
+    #[arg(long = "replay")]
+    replay: Option<PathBuf>,
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -77,6 +85,15 @@ fn main()
     let protocol_id = Rand64::new(env!("CARGO_PKG_VERSION"), 0u128).next();
     let factory = ClickClientFactory { protocol_id, resend_time: Duration::from_millis(100) };
 
+    // replay mode
+    if let Some(replay_path) = args.replay {
+        let replay = ClickGameReplay::load(&replay_path).expect("failed loading replay file");
+        let mut app = App::new();
+        add_replay_viewer(&mut app, factory, replay);
+        app.run();
+        return;
+    }
+
     let mut app = App::new();
     app.add_plugins(ClientInstancePlugin::new(factory, None))
         // Can't do this in OnEnter because it internally forces a state transition. State transitions can't be
@@ -0,0 +1,162 @@
+use std::net::Ipv6Addr;
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_girk_client_fw::ClientAppState;
+use bevy_girk_client_instance::*;
+use bevy_girk_game_fw::GameFwConfig;
+use bevy_girk_game_instance::{GameFactory, GameLaunchPack};
+use game_core::*;
+use renet2_setup::GameServerSetupConfig;
+use wiring_client_instance::ClickClientFactory;
+use wiring_game_instance::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Number of seconds to skip when seeking.
+const SEEK_SECS: u32 = 5;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn make_replay_game_configs(protocol_id: u64, recorded_ticks_per_sec: u32) -> ClickGameFactoryConfig
+{
+    // the replay app ticks faster than the recorded game so playback speed can be controlled
+    let game_ticks_per_sec = recorded_ticks_per_sec * REPLAY_TICK_MULTIPLIER;
+
+    // config
+    // - there are no remote clients to wait for
+    let max_init_ticks = game_ticks_per_sec * 5;
+    let game_over_ticks = game_ticks_per_sec * 3;
+
+    // server setup config
+    let server_setup_config = GameServerSetupConfig {
+        protocol_id,
+        expire_secs: 10u64,
+        timeout_secs: 5i32,
+        server_ip: Ipv6Addr::LOCALHOST.into(),
+        native_port: 0,
+        wasm_wt_port: 0,
+        wasm_ws_port: 0,
+        proxy_ip: None,
+        ws_domain: None,
+        wss_certs: None,
+        native_port_proxy: 0,
+        wasm_ws_port_proxy: 0,
+        wasm_wt_port_proxy: 0,
+        has_wss_proxy: false,
+    };
+
+    // game framework config
+    let game_fw_config = GameFwConfig::new(game_ticks_per_sec, max_init_ticks, game_over_ticks);
+
+    // click game factory config
+    // - game settings are overridden by the replay
+    ClickGameFactoryConfig {
+        server_setup_config,
+        game_fw_config,
+        duration_config: GameDurationConfig::new(0, 0),
+        game_mode: GameMode::Timed,
+        click_limit_config: ClickLimitConfig::new(1, 0, 2, 0.0),
+        pause_config: PauseConfig::default(),
+        chat_config: ChatConfig::new(200, 1, 0, false),
+        disconnect_config: DisconnectConfig::new(0),
+        replay_dir: None,
+        resend_time: Duration::from_millis(300),
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Starts (or restarts) the replay game when the client is idle.
+fn start_replay(mut c: Commands, mut viewer: ResMut<ReplayViewer>)
+{
+    if !viewer.pending_start {
+        return;
+    }
+    viewer.pending_start = false;
+    c.queue(ClientInstanceCommand::StartLocal(viewer.launch_pack.clone()));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn handle_replay_controls(
+    mut c: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    control: Res<ReplayControl>,
+    mut viewer: ResMut<ReplayViewer>,
+    app_state: Res<State<ClientAppState>>,
+)
+{
+    let seek_ticks = SEEK_SECS * viewer.recorded_ticks_per_sec;
+
+    if keys.just_pressed(KeyCode::Space) {
+        let paused = !control.is_paused();
+        control.set_paused(paused);
+        tracing::info!(paused, "replay pause toggled");
+    }
+
+    if keys.just_pressed(KeyCode::KeyD) {
+        let speed = match control.speed() {
+            ReplaySpeed::Normal => ReplaySpeed::Double,
+            ReplaySpeed::Double => ReplaySpeed::Normal,
+        };
+        control.set_speed(speed);
+        tracing::info!(?speed, "replay speed changed");
+    }
+
+    if keys.just_pressed(KeyCode::ArrowRight) {
+        let target = control.current_tick() + seek_ticks;
+        control.seek(target);
+        tracing::info!(target, "replay seeking forward");
+    }
+
+    if keys.just_pressed(KeyCode::ArrowLeft) {
+        // the game can't run backward, so restart it and fast-forward to the target
+        let target = control.current_tick().saturating_sub(seek_ticks);
+        control.restart_at(target);
+        if *app_state.get() == ClientAppState::Game {
+            c.queue(ClientInstanceCommand::Abort);
+        }
+        viewer.pending_start = true;
+        tracing::info!(target, "replay seeking backward");
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Resource)]
+struct ReplayViewer
+{
+    launch_pack: GameLaunchPack,
+    recorded_ticks_per_sec: u32,
+    pending_start: bool,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Sets up the app to play back a recorded game as a local game.
+///
+/// Controls:
+/// - `Space`: pause/resume
+/// - `D`: toggle 2x speed
+/// - `Right`/`Left`: seek forward/backward
+pub(crate) fn add_replay_viewer(app: &mut App, factory: ClickClientFactory, replay: ClickGameReplay)
+{
+    let control = ReplayControl::default();
+    let recorded_ticks_per_sec = replay.game_context.ticks_per_sec();
+    let launch_pack =
+        make_replay_launch_pack(&replay, make_replay_game_configs(factory.protocol_id, recorded_ticks_per_sec));
+    let game_factory = GameFactory::new(ClickReplayGameFactory { replay, control: control.clone() });
+
+    tracing::info!("replay controls: [Space] pause/resume, [D] toggle 2x speed, [Left/Right] seek {SEEK_SECS}s");
+
+    app.add_plugins(ClientInstancePlugin::new(factory, Some(game_factory)))
+        .insert_resource(control)
+        .insert_resource(ReplayViewer { launch_pack, recorded_ticks_per_sec, pending_start: true })
+        // Can't do this in OnEnter because it internally forces a state transition. State transitions can't be
+        // executed recursively.
+        .add_systems(PreUpdate, start_replay.run_if(in_state(ClientAppState::Client)))
+        .add_systems(Update, handle_replay_controls);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -1,3 +1,4 @@
+use std::collections::HashSet;
 use std::net::Ipv6Addr;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
@@ -23,6 +24,33 @@ struct PlaytestCli
     game: Option<String>,
     /// Specify the location of the game client binary (will use the debug build directory by default).
     client: Option<String>,
+    /// Give a client a handicap, as `CLIENT:POINTS_PER_CLICK:CLICK_COOLDOWN_TICKS` (e.g. `0:2:5`). May be
+    /// repeated.
+    #[arg(long = "handicap", value_parser = parse_handicap)]
+    handicaps: Vec<(u128, PlayerHandicap)>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn parse_handicap(arg: &str) -> Result<(u128, PlayerHandicap), String>
+{
+    let parts: Vec<&str> = arg.split(':').collect();
+    let [client, points_per_click, click_cooldown_ticks] = parts.as_slice() else {
+        return Err(format!("expected CLIENT:POINTS_PER_CLICK:CLICK_COOLDOWN_TICKS, got {arg:?}"));
+    };
+    let parse = |part: &str| part.parse::<u32>().map_err(|err| format!("invalid handicap {arg:?}: {err}"));
+    let client = parse(client)? as u128;
+    let handicap = PlayerHandicap {
+        points_per_click: parse(points_per_click)?,
+        click_cooldown_ticks: parse(click_cooldown_ticks)?,
+    };
+    if !ClickLobbyChecker::valid_handicap(&handicap) {
+        return Err(format!(
+            "handicap {arg:?} is out of bounds (max points per click: {MAX_POINTS_PER_CLICK}, \
+            max click cooldown ticks: {MAX_CLICK_COOLDOWN_TICKS})"
+        ));
+    }
+    Ok((client, handicap))
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -46,7 +74,6 @@ fn make_click_game_configs() -> ClickGameFactoryConfig
 {
     // game duration
     let game_ticks_per_sec = 20;
-    let game_num_ticks = game_ticks_per_sec * 30;
 
     // versioning
     //todo: use hasher directly?
@@ -54,7 +81,6 @@ fn make_click_game_configs() -> ClickGameFactoryConfig
 
     // config
     let max_init_ticks = game_ticks_per_sec * 5;
-    let game_prep_ticks = 0;
     let game_over_ticks = game_ticks_per_sec * 3;
 
     // server setup config
@@ -83,13 +109,45 @@ fn make_click_game_configs() -> ClickGameFactoryConfig
     let game_fw_config = GameFwConfig::new(game_ticks_per_sec, max_init_ticks, game_over_ticks);
 
     // game duration config
-    let duration_config = GameDurationConfig::new(game_prep_ticks, game_num_ticks);
+    // - prep and play durations are replaced by the lobby's game settings when a game launches
+    // - tied games go to sudden-death overtime for up to 10 seconds
+    let duration_config = GameDurationConfig::new(
+        DEFAULT_PREP_SECS as u32 * game_ticks_per_sec,
+        DEFAULT_GAME_SECS as u32 * game_ticks_per_sec,
+    )
+    .with_overtime(game_ticks_per_sec * 10);
+
+    // click limit config
+    // - at most 15 clicks per second
+    // - flag players whose last 20 click intervals are nearly identical
+    let click_limit_config = ClickLimitConfig::new(game_ticks_per_sec, 15, 20, 0.05);
+
+    // pause config
+    // - pauses need a majority vote
+    // - each player can start 2 pauses, which resume automatically after 30 seconds
+    let pause_config = PauseConfig::new(PauseMode::Vote, 2, game_ticks_per_sec * 30);
+
+    // chat config
+    // - messages up to 200 characters
+    // - at most 5 messages every 10 seconds
+    // - watchers can only chat with other watchers
+    let chat_config = ChatConfig::new(200, game_ticks_per_sec * 10, 5, false);
+
+    // disconnect config
+    // - disconnected players forfeit if they don't reconnect within 30 seconds
+    let disconnect_config = DisconnectConfig::new(game_ticks_per_sec * 30);
 
     // click game factory config
     let game_factory_config = ClickGameFactoryConfig {
         server_setup_config,
         game_fw_config,
         duration_config,
+        game_mode: GameMode::Timed,
+        click_limit_config,
+        pause_config,
+        chat_config,
+        disconnect_config,
+        replay_dir: None,
         resend_time: Duration::from_millis(300),
     };
 
@@ -229,7 +287,19 @@ fn main()
     let lobby_contents = ClickLobbyContents {
         id: 0u64,
         owner_id: 0u128,
-        config: ClickLobbyConfig { max_players: num_clients as u16, max_watchers: 0u16 },
+        config: ClickLobbyConfig {
+            max_players: num_clients as u16,
+            max_watchers: 0u16,
+            num_teams: 0u8,
+            bot_difficulty: None,
+            hidden_scores: false,
+            handicaps: args.handicaps.into_iter().collect(),
+            prep_secs: DEFAULT_PREP_SECS,
+            game_secs: DEFAULT_GAME_SECS,
+            game_mode: ClickLobbyGameMode::default(),
+            owner_name: None,
+            banned_users: HashSet::default(),
+        },
         players,
         watchers: Vec::default(),
     };
@@ -0,0 +1,96 @@
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+use bevy_girk_client_fw::{ClientAppState, ClientSender};
+use game_core::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Max number of messages kept in the [`ChatLog`].
+const CHAT_LOG_CAPACITY: usize = 50;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Adds a chat message sent by the game to the chat log.
+pub(crate) fn handle_chat(In((from, text)): In<(ClientId, String)>, mut log: ResMut<ChatLog>)
+{
+    if log.messages.len() >= CHAT_LOG_CAPACITY {
+        log.messages.pop_front();
+    }
+    log.messages.push_back(ChatMessage { from, text });
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Sends a chat message to the game. Empty messages are ignored.
+///
+/// Players and watchers can both chat.
+pub fn send_chat(In(text): In<String>, mut sender: ClientSender)
+{
+    let text = text.trim();
+    if text.is_empty() {
+        return;
+    }
+
+    sender.send(ClientRequest::Chat { text: text.into() });
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn reset_chat_log(mut log: ResMut<ChatLog>)
+{
+    *log = ChatLog::default();
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A chat message received from the game.
+#[derive(Debug, Clone)]
+pub struct ChatMessage
+{
+    /// The client that sent the message.
+    pub from: ClientId,
+    pub text: String,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Recent chat messages, oldest first.
+#[derive(Resource, Default, Debug)]
+pub struct ChatLog
+{
+    messages: VecDeque<ChatMessage>,
+}
+
+impl ChatLog
+{
+    pub fn len(&self) -> usize
+    {
+        self.messages.len()
+    }
+
+    pub fn is_empty(&self) -> bool
+    {
+        self.messages.is_empty()
+    }
+
+    pub fn get(&self, idx: usize) -> Option<&ChatMessage>
+    {
+        self.messages.get(idx)
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct ChatLogPlugin;
+
+impl Plugin for ChatLogPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.init_resource::<ChatLog>()
+            .add_systems(OnExit(ClientAppState::Game), reset_chat_log);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
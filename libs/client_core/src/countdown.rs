@@ -0,0 +1,68 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_girk_client_fw::ClientAppState;
+use game_core::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Syncs the countdown with a state timer sent by the game.
+pub(crate) fn handle_state_timer(
+    In((game_state, remaining_ticks, ticks_per_sec)): In<(GameState, u32, u32)>,
+    time: Res<Time>,
+    mut countdown: ResMut<StateCountdown>,
+)
+{
+    let remaining = Duration::from_secs_f32(remaining_ticks as f32 / ticks_per_sec.max(1) as f32);
+    countdown.game_state = Some(game_state);
+    countdown.end_time = time.elapsed() + remaining;
+    tracing::debug!(?game_state, remaining_ticks, "synced state countdown");
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn reset_countdown(mut countdown: ResMut<StateCountdown>)
+{
+    *countdown = StateCountdown::default();
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Time remaining in the current [`GameState::Prep`] or [`GameState::Play`] phase.
+///
+/// Synced with the server whenever a phase starts and when the client requests the game state.
+#[derive(Resource, Default, Debug)]
+pub struct StateCountdown
+{
+    game_state: Option<GameState>,
+    end_time: Duration,
+}
+
+impl StateCountdown
+{
+    /// Returns the time remaining in `game_state`.
+    ///
+    /// Returns `None` if the countdown isn't synced to `game_state`.
+    pub fn remaining(&self, game_state: GameState, time: &Time) -> Option<Duration>
+    {
+        if self.game_state != Some(game_state) {
+            return None;
+        }
+        Some(self.end_time.saturating_sub(time.elapsed()))
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct StateCountdownPlugin;
+
+impl Plugin for StateCountdownPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.init_resource::<StateCountdown>()
+            .add_systems(OnExit(ClientAppState::Game), reset_countdown);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
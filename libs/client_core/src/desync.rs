@@ -0,0 +1,114 @@
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+use bevy_girk_client_fw::{ClientAppState, ClientSender};
+use game_core::*;
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Max number of replicated state hashes to remember.
+const STATE_HISTORY_LEN: usize = 64;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Buffers a state hash sent by the game until the client's replicated state reaches the hash's tick.
+pub(crate) fn handle_state_hash(In((tick, expected)): In<(u32, u64)>, mut checker: ResMut<DesyncChecker>)
+{
+    checker.pending.push_back((tick, expected));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Records a hash of the client's replicated state whenever the replicated [`StateTick`] changes.
+fn record_state_hash(
+    state_tick: Query<&StateTick, Changed<StateTick>>,
+    state: ReplicatedPlayerState,
+    mut checker: ResMut<DesyncChecker>,
+)
+{
+    let Ok(state_tick) = state_tick.single() else { return };
+
+    // the game only hashes state that is visible to this client
+    let hash = state.hash(|_| true);
+    checker.history.push_back((state_tick.tick, hash));
+    if checker.history.len() > STATE_HISTORY_LEN {
+        checker.history.pop_front();
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Compares buffered state hashes against replicated state from the same tick.
+///
+/// Mismatches are logged and reported to the game.
+fn compare_state_hashes(mut checker: ResMut<DesyncChecker>, mut sender: ClientSender)
+{
+    let Some(latest_tick) = checker.history.back().map(|(tick, _)| *tick) else { return };
+
+    let checker = &mut *checker;
+    while let Some((tick, expected)) = checker.pending.front().copied() {
+        // wait for replicated state to catch up
+        if tick > latest_tick {
+            break;
+        }
+        checker.pending.pop_front();
+
+        // replication can skip ticks, in which case the hash can't be checked
+        let Some((_, found)) = checker.history.iter().find(|(state_tick, _)| *state_tick == tick) else {
+            tracing::trace!(tick, "no replicated state for state hash, skipping");
+            continue;
+        };
+        if *found == expected {
+            continue;
+        }
+
+        let found = *found;
+        tracing::warn!(tick, expected, found, "replicated state desynced from the game");
+        sender.send(ClientRequest::ReportDesync { tick, expected, found });
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn reset_desync_checker(mut checker: ResMut<DesyncChecker>)
+{
+    *checker = DesyncChecker::default();
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Tracks state hashes sent by the game and hashes of the client's replicated state.
+#[derive(Resource, Default, Debug)]
+pub(crate) struct DesyncChecker
+{
+    /// (game tick, hash) sent by the game that haven't been checked yet, oldest first.
+    pending: VecDeque<(u32, u64)>,
+    /// (game tick, hash) of recent replicated states, oldest first.
+    history: VecDeque<(u32, u64)>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Detects desyncs between the game and the client's replicated state.
+pub(crate) struct DesyncPlugin;
+
+impl Plugin for DesyncPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.init_resource::<DesyncChecker>()
+            .add_systems(
+                Update,
+                (record_state_hash, compare_state_hashes)
+                    .chain()
+                    .in_set(ClientLogicSet::Admin)
+                    .after(apply_replicated_scores)
+                    .run_if(not(in_state(ClientState::Init))),
+            )
+            .add_systems(OnExit(ClientAppState::Game), reset_desync_checker);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -7,13 +7,6 @@ use crate::*;
 
 //-------------------------------------------------------------------------------------------------------------------
 
-fn handle_request_rejected(request: ClientRequest, reason: RejectionReason)
-{
-    tracing::warn!("game request {request:?} rejected: {reason:?}");
-}
-
-//-------------------------------------------------------------------------------------------------------------------
-
 /// Handle a message sent to the client from the game.
 ///
 /// Note: this function is meant to be injected to a [`GameMessageHandler`], where it will be invoked by the client
@@ -23,8 +16,26 @@ pub(crate) fn handle_game_message(world: &mut World, _tick: Tick, message: GameM
     let _state = **world.resource::<State<ClientState>>();
 
     match message {
-        GameMsg::RequestRejected { reason, request } => handle_request_rejected(request, reason),
+        GameMsg::RequestRejected { reason, request } => {
+            if let ClientRequest::PlayerInput(_) = request {
+                world.syscall((), handle_rejected_input);
+            }
+            world.syscall((request, reason), handle_request_rejected);
+        }
         GameMsg::CurrentGameState(game_state) => world.syscall(game_state, handle_game_state),
+        GameMsg::StateTimer { game_state, remaining_ticks, ticks_per_sec } => {
+            world.syscall((game_state, remaining_ticks, ticks_per_sec), handle_state_timer)
+        }
+        GameMsg::PauseVotes { pause_votes, resume_votes, votes_needed } => {
+            world.syscall((pause_votes, resume_votes, votes_needed), handle_pause_votes)
+        }
+        GameMsg::Overtime { players, remaining_ticks, ticks_per_sec } => {
+            // overtime extends play, so the play countdown shows the time left in overtime
+            world.syscall((GameState::Play, remaining_ticks, ticks_per_sec), handle_state_timer);
+            world.syscall(players, handle_overtime);
+        }
+        GameMsg::StateHash { tick, hash } => world.syscall((tick, hash), handle_state_hash),
+        GameMsg::Chat { from, text } => world.syscall((from, text), handle_chat),
     }
 }
 
@@ -26,6 +26,7 @@ fn update_client_state(
         GameState::Startup | GameState::Init => ClientState::Init,
         GameState::Prep => ClientState::Prep,
         GameState::Play => ClientState::Play,
+        GameState::Paused => ClientState::Paused,
         GameState::GameOver => ClientState::GameOver,
     };
 
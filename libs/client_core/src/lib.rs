@@ -1,13 +1,29 @@
+mod chat;
+mod countdown;
+mod desync;
 mod game_channel;
+mod overtime;
+mod pause;
 mod player_inputs;
 mod plugin;
+mod prediction;
+mod rejections;
+mod scores;
 mod sets;
 mod setup;
 mod states;
 
+pub use chat::*;
+pub use countdown::*;
+pub(crate) use desync::*;
 pub(crate) use game_channel::*;
+pub use overtime::*;
+pub use pause::*;
 pub use player_inputs::*;
 pub use plugin::*;
+pub use prediction::*;
+pub use rejections::*;
+pub(crate) use scores::*;
 pub use sets::*;
 pub(crate) use setup::*;
 pub use states::*;
@@ -0,0 +1,66 @@
+use bevy::prelude::*;
+use bevy_girk_client_fw::ClientAppState;
+use game_core::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Records the players in sudden-death overtime.
+///
+/// The overtime countdown is synced separately through the [`StateCountdown`](crate::StateCountdown).
+pub(crate) fn handle_overtime(In(players): In<Vec<ClientId>>, mut overtime: ResMut<Overtime>)
+{
+    tracing::debug!(?players, "overtime started");
+    overtime.active = true;
+    overtime.players = players;
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn reset_overtime(mut overtime: ResMut<Overtime>)
+{
+    *overtime = Overtime::default();
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Sudden-death overtime status.
+///
+/// Only the players tied for first may score in overtime, and the first of them to click wins.
+#[derive(Resource, Default, Debug)]
+pub struct Overtime
+{
+    active: bool,
+    players: Vec<ClientId>,
+}
+
+impl Overtime
+{
+    /// Returns `true` if the game went to overtime.
+    pub fn is_active(&self) -> bool
+    {
+        self.active
+    }
+
+    /// The players competing in overtime.
+    ///
+    /// While scores are hidden, only tied players are told about overtime, so this only contains the local player.
+    pub fn players(&self) -> &[ClientId]
+    {
+        &self.players
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct OvertimePlugin;
+
+impl Plugin for OvertimePlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.init_resource::<Overtime>()
+            .add_systems(OnExit(ClientAppState::Game), reset_overtime);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,92 @@
+use bevy::prelude::*;
+use bevy_girk_client_fw::{ClientAppState, ClientSender};
+use game_core::*;
+use wiring_game_instance::{ClientContext, ClientType};
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Syncs pause votes with the game.
+pub(crate) fn handle_pause_votes(
+    In((pause_votes, resume_votes, votes_needed)): In<(u32, u32, u32)>,
+    mut votes: ResMut<PauseVotes>,
+)
+{
+    *votes = PauseVotes { pause_votes, resume_votes, votes_needed };
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn send_pause_request(request: ClientRequest, context: &ClientContext, sender: &mut ClientSender)
+{
+    if context.client_type() != ClientType::Player {
+        tracing::warn!("ignoring pause request sent by a non-player client: {request:?}");
+        return;
+    }
+
+    sender.send(request);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Asks the game to pause. Only players can pause the game.
+pub fn request_pause(mut sender: ClientSender, context: Res<ClientContext>)
+{
+    send_pause_request(ClientRequest::RequestPause, &context, &mut sender);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Asks the game to resume. Only players can resume the game.
+pub fn request_resume(mut sender: ClientSender, context: Res<ClientContext>)
+{
+    send_pause_request(ClientRequest::RequestResume, &context, &mut sender);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn reset_pause_votes(mut votes: ResMut<PauseVotes>)
+{
+    *votes = PauseVotes::default();
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Latest pause and resume votes sent by the game.
+#[derive(Resource, Default, Debug, Copy, Clone)]
+pub struct PauseVotes
+{
+    pause_votes: u32,
+    resume_votes: u32,
+    votes_needed: u32,
+}
+
+impl PauseVotes
+{
+    pub fn pause_votes(&self) -> u32
+    {
+        self.pause_votes
+    }
+    pub fn resume_votes(&self) -> u32
+    {
+        self.resume_votes
+    }
+    pub fn votes_needed(&self) -> u32
+    {
+        self.votes_needed
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct PauseVotesPlugin;
+
+impl Plugin for PauseVotesPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.init_resource::<PauseVotes>()
+            .add_systems(OnExit(ClientAppState::Game), reset_pause_votes);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -43,7 +43,10 @@ fn process_player_inputs(
 fn handle_input(world: &mut World, input: PlayerInput, state: ClientState)
 {
     match state {
-        ClientState::Play => world.syscall(ClientRequest::PlayerInput(input), send_client_request),
+        ClientState::Play => {
+            world.syscall(input, predict_click);
+            world.syscall(ClientRequest::PlayerInput(input), send_client_request);
+        }
         _ => {
             tracing::warn!("ignoring invalid input sent during {state:?}: {input:?}");
         }
@@ -64,6 +67,65 @@ pub(crate) fn handle_player_inputs(world: &mut World)
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Converts inputs that were just pressed to player inputs using the [`InputBindings`].
+fn collect_bound_inputs(
+    bindings: Res<InputBindings>,
+    context: Res<ClientContext>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    gamepads: Query<&Gamepad>,
+) -> Vec<PlayerInput>
+{
+    let pressed = keys
+        .get_just_pressed()
+        .map(|key| InputBinding::Key(*key))
+        .chain(mouse.get_just_pressed().map(|button| InputBinding::Mouse(*button)))
+        .chain(
+            gamepads
+                .iter()
+                .flat_map(|gamepad| gamepad.get_just_pressed().map(|button| InputBinding::Gamepad(*button))),
+        );
+
+    let mut inputs = Vec::default();
+    for action in pressed.filter_map(|binding| bindings.action(binding)) {
+        match action {
+            InputAction::Click => {
+                // targets must be aimed at with the pointer, otherwise a bound key would hit them automatically
+                if context.game_mode().has_targets() {
+                    continue;
+                }
+                inputs.push(PlayerInput::ClickButton);
+            }
+        }
+    }
+    inputs
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Handle inputs from bound keys and buttons for ClientMode::Play.
+pub(crate) fn handle_bound_inputs(world: &mut World)
+{
+    if **world.resource::<BindingsSuspended>() {
+        return;
+    }
+    if world.resource::<ClientContext>().client_type() != ClientType::Player {
+        return;
+    }
+    let Some(state) = world.get_resource::<State<ClientState>>().map(|state| **state) else {
+        return;
+    };
+    if state != ClientState::Play {
+        return;
+    }
+
+    for input in world.syscall((), collect_bound_inputs) {
+        handle_input(world, input, state);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 pub(crate) fn clear_player_inputs(world: &mut World)
 {
     process_player_inputs(world, ClientState::Init, |_, _, _| {});
@@ -0,0 +1,163 @@
+use std::fmt;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// An input that can be bound to an [`InputAction`].
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum InputBinding
+{
+    Key(KeyCode),
+    /// Note: [`MouseButton::Left`] can't be bound because it is used to interact with the UI.
+    Mouse(MouseButton),
+    Gamepad(GamepadButton),
+}
+
+impl InputBinding
+{
+    /// Returns `true` if the binding may be used.
+    pub fn is_allowed(&self) -> bool
+    {
+        *self != Self::Mouse(MouseButton::Left)
+    }
+}
+
+impl fmt::Display for InputBinding
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self {
+            Self::Key(key) => write!(f, "{key:?}"),
+            Self::Mouse(button) => write!(f, "Mouse {button:?}"),
+            Self::Gamepad(button) => write!(f, "Gamepad {button:?}"),
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Player actions that can be bound to inputs.
+///
+/// Actions are converted to [`PlayerInput`](game_core::PlayerInput)s based on the game mode.
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum InputAction
+{
+    /// Click the button.
+    ///
+    /// Does nothing in [`GameMode::Targets`](game_core::GameMode::Targets), where targets must be clicked with the
+    /// pointer.
+    Click,
+}
+
+impl InputAction
+{
+    /// All bindable actions, in display order.
+    pub const ALL: [InputAction; 1] = [InputAction::Click];
+
+    /// Gets the display name of the action.
+    pub fn name(&self) -> &'static str
+    {
+        match self {
+            Self::Click => "Click",
+        }
+    }
+
+    /// Gets the default bindings for the action.
+    pub fn default_bindings(&self) -> Vec<InputBinding>
+    {
+        match self {
+            Self::Click => vec![
+                InputBinding::Key(KeyCode::Space),
+                InputBinding::Gamepad(GamepadButton::South),
+            ],
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Maps keyboard keys, mouse buttons, and gamepad buttons to [`InputAction`]s.
+///
+/// Bindings are stored in the user's settings.
+#[derive(Resource, Debug, Clone, Serialize, Deserialize)]
+pub struct InputBindings
+{
+    bindings: Vec<(InputBinding, InputAction)>,
+}
+
+impl InputBindings
+{
+    /// Gets the inputs bound to an action.
+    pub fn get(&self, action: InputAction) -> impl Iterator<Item = InputBinding> + '_
+    {
+        self.bindings
+            .iter()
+            .filter(move |(_, bound)| *bound == action)
+            .map(|(binding, _)| *binding)
+    }
+
+    /// Gets the action bound to an input.
+    pub fn action(&self, binding: InputBinding) -> Option<InputAction>
+    {
+        self.bindings
+            .iter()
+            .find(|(bound, _)| *bound == binding)
+            .map(|(_, action)| *action)
+    }
+
+    /// Binds an input to an action. The input is unbound from any other action.
+    ///
+    /// Returns `false` if the input isn't allowed to be bound.
+    pub fn bind(&mut self, binding: InputBinding, action: InputAction) -> bool
+    {
+        if !binding.is_allowed() {
+            return false;
+        }
+        self.bindings.retain(|(bound, _)| *bound != binding);
+        self.bindings.push((binding, action));
+        true
+    }
+
+    /// Removes all bindings of an action.
+    pub fn clear(&mut self, action: InputAction)
+    {
+        self.bindings.retain(|(_, bound)| *bound != action);
+    }
+
+    /// Restores the default bindings of an action.
+    pub fn reset(&mut self, action: InputAction)
+    {
+        self.clear(action);
+        for binding in action.default_bindings() {
+            self.bind(binding, action);
+        }
+    }
+
+    /// Iterates over all bindings.
+    pub fn iter(&self) -> impl Iterator<Item = (InputBinding, InputAction)> + '_
+    {
+        self.bindings.iter().copied()
+    }
+}
+
+impl Default for InputBindings
+{
+    fn default() -> Self
+    {
+        let mut bindings = Self { bindings: Vec::default() };
+        for action in InputAction::ALL {
+            bindings.reset(action);
+        }
+        bindings
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Suspends [`InputBindings`], e.g. while the user is typing into a text field.
+#[derive(Resource, Default, Debug, Copy, Clone, Eq, PartialEq, Deref, DerefMut)]
+pub struct BindingsSuspended(pub bool);
+
+//-------------------------------------------------------------------------------------------------------------------
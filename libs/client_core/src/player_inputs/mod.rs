@@ -1,5 +1,7 @@
 mod handle_player_inputs;
+mod input_bindings;
 mod plugin;
 
 pub(crate) use handle_player_inputs::*;
+pub use input_bindings::*;
 pub use plugin::*;
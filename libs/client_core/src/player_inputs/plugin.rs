@@ -24,15 +24,18 @@ pub struct PlayerInputSet;
 
 /// Player input plugin.
 ///
-/// Sets up systems for marshalling player inputs to the game instance.
+/// Sets up systems for marshalling player inputs to the game instance. Inputs come from the
+/// `Receiver<PlayerInput>` and from keys and buttons mapped by the [`InputBindings`].
 pub(crate) struct PlayerInputPlugin;
 
 impl Plugin for PlayerInputPlugin
 {
     fn build(&self, app: &mut App)
     {
-        app.add_systems(OnEnter(ClientAppState::Game), prestartup_check)
-            .add_systems(Update, handle_player_inputs.in_set(PlayerInputSet))
+        app.init_resource::<InputBindings>()
+            .init_resource::<BindingsSuspended>()
+            .add_systems(OnEnter(ClientAppState::Game), prestartup_check)
+            .add_systems(Update, (handle_player_inputs, handle_bound_inputs).in_set(PlayerInputSet))
             .add_systems(OnEnter(ClientAppState::Game), clear_player_inputs);
     }
 }
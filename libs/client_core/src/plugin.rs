@@ -32,6 +32,14 @@ impl Plugin for ClientCorePlugin
         app.add_plugins(GameReplicationPlugin)
             .add_plugins(ClientSetsPlugin)
             .add_plugins(ClientSetupPlugin)
+            .add_plugins(StateCountdownPlugin)
+            .add_plugins(ChatLogPlugin)
+            .add_plugins(DesyncPlugin)
+            .add_plugins(OvertimePlugin)
+            .add_plugins(PauseVotesPlugin)
+            .add_plugins(RejectionsPlugin)
+            .add_plugins(ReplicatedScoresPlugin)
+            .add_plugins(ScorePredictionPlugin)
             // For this demo we assume watcher clients will re-use the player skin, which depends on
             // `PlayerInputPlugin`. A different project may want to completely separate player and
             // watcher skins, in which case this plugin can go in a player-client-specific crate.
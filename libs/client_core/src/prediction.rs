@@ -0,0 +1,164 @@
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+use bevy_girk_client_fw::ClientAppState;
+use game_core::*;
+use wiring_game_instance::ClientContext;
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Optimistically adds a click to the predicted score.
+///
+/// Clicks by eliminated or forfeited players are never accepted, so they aren't predicted.
+pub(crate) fn predict_click(
+    In(_input): In<PlayerInput>,
+    context: Res<ClientContext>,
+    players: Query<(&PlayerId, &PlayerHandicap, &PlayerConnection, Has<PlayerEliminated>)>,
+    mut prediction: ResMut<PredictedScore>,
+)
+{
+    let Some((_, handicap, connection, eliminated)) = players
+        .iter()
+        .find(|(player_id, ..)| player_id.id == context.id())
+    else {
+        return;
+    };
+    if eliminated || connection.is_forfeited() {
+        return;
+    }
+
+    prediction.pending.push_back(handicap.points_per_click);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Removes a predicted click after the game rejected a player input.
+///
+/// Inputs are acknowledged in bulk by score changes, so it doesn't matter which pending click is removed.
+pub(crate) fn handle_rejected_input(mut prediction: ResMut<PredictedScore>)
+{
+    if prediction.pending.pop_front().is_none() {
+        tracing::debug!("received input rejection with no predicted clicks");
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Acknowledges predicted clicks when the player's replicated score catches up.
+fn reconcile_predicted_score(
+    context: Res<ClientContext>,
+    players: Query<(Entity, &PlayerId, &PlayerScore), Changed<PlayerScore>>,
+    mut prediction: ResMut<PredictedScore>,
+)
+{
+    let Some((player, _, score)) = players
+        .iter()
+        .find(|(_, player_id, _)| player_id.id == context.id())
+    else {
+        return;
+    };
+    let score = score.score();
+
+    // the first replicated score is the baseline
+    if prediction.player != Some(player) {
+        prediction.player = Some(player);
+        prediction.confirmed = score;
+        return;
+    }
+
+    // scores only go up, so a lower score means our view of the game was reset
+    if score < prediction.confirmed {
+        let confirmed = prediction.confirmed;
+        tracing::warn!(score, confirmed, "replicated score decreased, dropping predictions");
+        prediction.pending.clear();
+        prediction.confirmed = score;
+        return;
+    }
+
+    let mut unacked = score - prediction.confirmed;
+    while let Some(points) = prediction.pending.front().copied() {
+        if points > unacked {
+            break;
+        }
+        unacked -= points;
+        prediction.pending.pop_front();
+    }
+    prediction.confirmed = score;
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Drops unacknowledged clicks when the game ends.
+fn clear_pending_clicks(mut prediction: ResMut<PredictedScore>)
+{
+    prediction.pending.clear();
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn reset_predicted_score(mut prediction: ResMut<PredictedScore>)
+{
+    *prediction = PredictedScore::default();
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// The local player's score including clicks that haven't been acknowledged by the game yet.
+///
+/// Predicted clicks are acknowledged when the replicated [`PlayerScore`] increases, and removed if the game
+/// rejects a player input.
+#[derive(Resource, Default, Debug)]
+pub struct PredictedScore
+{
+    player: Option<Entity>,
+    /// Latest replicated score.
+    confirmed: u32,
+    /// Points of each unacknowledged click, oldest first.
+    pending: VecDeque<u32>,
+}
+
+impl PredictedScore
+{
+    /// The local player's entity, if their score has been replicated.
+    pub fn player(&self) -> Option<Entity>
+    {
+        self.player
+    }
+
+    /// The predicted score.
+    pub fn score(&self) -> u32
+    {
+        self.confirmed + self.pending.iter().sum::<u32>()
+    }
+
+    /// Number of clicks waiting to be acknowledged by the game.
+    pub fn num_pending(&self) -> usize
+    {
+        self.pending.len()
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct ScorePredictionPlugin;
+
+impl Plugin for ScorePredictionPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.init_resource::<PredictedScore>()
+            .add_systems(
+                Update,
+                reconcile_predicted_score
+                    .in_set(ClientLogicSet::Admin)
+                    .after(apply_replicated_scores)
+                    .run_if(not(in_state(ClientState::Init))),
+            )
+            .add_systems(OnEnter(ClientState::GameOver), clear_pending_clicks)
+            .add_systems(OnExit(ClientAppState::Game), reset_predicted_score);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
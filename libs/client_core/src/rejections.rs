@@ -0,0 +1,77 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use bevy_cobweb::prelude::*;
+use bevy_girk_client_fw::ClientAppState;
+use game_core::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Counts and broadcasts a request rejected by the game.
+pub(crate) fn handle_request_rejected(
+    In((request, reason)): In<(ClientRequest, RejectionReason)>,
+    mut c: Commands,
+    mut counts: ResMut<RejectionCounts>,
+)
+{
+    tracing::warn!("game request {request:?} rejected: {reason:?}");
+
+    *counts.counts.entry(reason).or_default() += 1;
+    c.react().broadcast(RequestRejected { request, reason });
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn reset_rejection_counts(mut counts: ResMut<RejectionCounts>)
+{
+    *counts = RejectionCounts::default();
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Event broadcast when the game rejects a request sent by this client.
+#[derive(Debug, Clone)]
+pub struct RequestRejected
+{
+    pub request: ClientRequest,
+    pub reason: RejectionReason,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Number of requests rejected by the game in the current game, for diagnostics.
+#[derive(Resource, Default, Debug)]
+pub struct RejectionCounts
+{
+    counts: HashMap<RejectionReason, u32>,
+}
+
+impl RejectionCounts
+{
+    /// Number of requests rejected for `reason`.
+    pub fn get(&self, reason: RejectionReason) -> u32
+    {
+        self.counts.get(&reason).copied().unwrap_or_default()
+    }
+
+    /// Total number of rejected requests.
+    pub fn total(&self) -> u32
+    {
+        self.counts.values().sum()
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct RejectionsPlugin;
+
+impl Plugin for RejectionsPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.init_resource::<RejectionCounts>()
+            .add_systems(OnExit(ClientAppState::Game), reset_rejection_counts);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
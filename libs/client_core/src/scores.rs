@@ -0,0 +1,32 @@
+use bevy::prelude::*;
+use game_core::*;
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Copies replicated scores onto their player entities.
+///
+/// Scores are replicated separately from players so they can be hidden, so players whose score isn't visible don't
+/// have a [`PlayerScore`].
+pub(crate) fn apply_replicated_scores(mut c: Commands, scores: Query<&ReplicatedScore, Changed<ReplicatedScore>>)
+{
+    for replicated in scores.iter() {
+        let Ok(mut entity_commands) = c.get_entity(replicated.player) else { continue };
+        entity_commands.insert(replicated.score);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct ReplicatedScoresPlugin;
+
+impl Plugin for ReplicatedScoresPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.add_systems(Update, apply_replicated_scores.in_set(ClientLogicSet::Admin));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -17,6 +17,8 @@ pub enum ClientSet
     Prep,
     /// Runs in game mode 'play' (but not when initializing).
     Play,
+    /// Runs in game mode 'paused' (but not when initializing).
+    Paused,
     /// Runs in game mode 'game over' (but not when initializing).
     GameOver,
 }
@@ -61,6 +63,12 @@ impl Plugin for ClientSetsPlugin
                 .run_if(in_state(ClientFwState::Game))
                 .run_if(in_state(ClientState::Play)),
         )
+        .configure_sets(
+            Update,
+            ClientSet::Paused
+                .run_if(in_state(ClientFwState::Game))
+                .run_if(in_state(ClientState::Paused)),
+        )
         .configure_sets(
             Update,
             ClientSet::GameOver
@@ -12,6 +12,7 @@ pub enum ClientState
     Init,
     Prep,
     Play,
+    Paused,
     GameOver,
 }
 
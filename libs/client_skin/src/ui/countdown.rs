@@ -0,0 +1,77 @@
+use bevy::prelude::*;
+use bevy_cobweb::prelude::*;
+use bevy_cobweb_ui::prelude::*;
+use client_core::*;
+use game_core::GameState;
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Default)]
+pub(crate) struct RefreshCountdown;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Writes the remaining time in `game_state` to a text entity, rounded up to whole seconds.
+///
+/// The text is only rewritten when the displayed value changes.
+pub(crate) fn edit_countdown_text(
+    mut h: UiSceneHandle,
+    game_state: GameState,
+    format: fn(u64) -> String,
+)
+{
+    h.update_on(
+        broadcast::<RefreshCountdown>(),
+        move |//
+            id: TargetId,
+            mut prev: Local<Option<u64>>,
+            mut e: TextEditor,
+            time: Res<Time>,
+            countdown: Res<StateCountdown>,
+            //
+        |
+        {
+            let Some(remaining) = countdown.remaining(game_state, &time) else { return };
+            let secs = remaining.as_secs() + u64::from(remaining.subsec_nanos() > 0);
+            if *prev == Some(secs) {
+                return;
+            }
+            *prev = Some(secs);
+            write_text!(e, *id, "{}", format(secs));
+        },
+    );
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn add_prep_overlay(mut c: Commands, mut s: SceneBuilder)
+{
+    let scene = ("ui.skin", "prep_countdown");
+    c.ui_root().spawn_scene(scene, &mut s, |h| {
+        h.insert(StateScoped(ClientState::Prep));
+
+        edit_countdown_text(h.get("text"), GameState::Prep, |secs| format!("{}", secs.max(1)));
+    });
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(super) struct CountdownPlugin;
+
+impl Plugin for CountdownPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.add_systems(OnEnter(ClientState::Prep), add_prep_overlay)
+            .add_systems(
+                Update,
+                broadcast_system::<RefreshCountdown>
+                    .in_set(ClientLogicSet::End)
+                    .run_if(in_state(ClientState::Prep).or(in_state(ClientState::Play))),
+            );
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,177 @@
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::prelude::*;
+use bevy_cobweb::prelude::*;
+use bevy_cobweb_ui::prelude::*;
+use bevy_girk_client_fw::ClientAppState;
+use client_core::*;
+use game_core::*;
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Max number of chat messages shown in the chat panel.
+const CHAT_VISIBLE_MESSAGES: usize = 8;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Default)]
+struct RefreshChat;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Message being typed into the chat panel.
+///
+/// Press Enter to start typing, and Enter again to send. Press Escape to cancel. Input bindings are suspended
+/// while typing.
+#[derive(Resource, Default, Debug)]
+struct ChatDraft
+{
+    typing: bool,
+    text: String,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn edit_chat_draft(
+    mut c: Commands,
+    mut keys: EventReader<KeyboardInput>,
+    mut draft: ResMut<ChatDraft>,
+    mut suspended: ResMut<BindingsSuspended>,
+)
+{
+    for key in keys.read() {
+        if !key.state.is_pressed() {
+            continue;
+        }
+
+        match &key.logical_key {
+            Key::Enter => {
+                if draft.typing {
+                    let text = std::mem::take(&mut draft.text);
+                    c.syscall(text, send_chat);
+                }
+                draft.typing = !draft.typing;
+            }
+            Key::Escape => {
+                draft.typing = false;
+                draft.text.clear();
+            }
+            _ if !draft.typing => (),
+            Key::Backspace => {
+                draft.text.pop();
+            }
+            Key::Space => draft.text.push(' '),
+            Key::Character(chars) => draft.text.push_str(chars),
+            _ => (),
+        }
+    }
+
+    if **suspended != draft.typing {
+        **suspended = draft.typing;
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn reset_chat_draft(mut draft: ResMut<ChatDraft>, mut suspended: ResMut<BindingsSuspended>)
+{
+    *draft = ChatDraft::default();
+    **suspended = false;
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Gets the display name of a chat message's sender.
+fn sender_name(from: ClientId, players: &Query<(&PlayerId, &PlayerName)>) -> String
+{
+    players
+        .iter()
+        .find(|(id, _)| id.id == from)
+        .map(|(_, name)| name.name.clone())
+        .unwrap_or_else(|| format!("watcher{}", from))
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(super) fn edit_chat(mut h: UiSceneHandle)
+{
+    // Add message lines as messages arrive. Lines show the most recent messages, oldest first.
+    h.get("messages").update_on(
+        broadcast::<RefreshChat>(),
+        |//
+            id: TargetId,
+            mut num_lines: Local<usize>,
+            mut c: Commands,
+            mut s: SceneBuilder,
+            log: Res<ChatLog>,
+            //
+        |
+        {
+            let mut builder = c.ui_builder(*id);
+            let line_item = ("ui.skin.game", "chat_line");
+
+            while *num_lines < log.len().min(CHAT_VISIBLE_MESSAGES) {
+                let line = *num_lines;
+                builder.spawn_scene(line_item, &mut s, |h| {
+                    // Lines are never despawned so it's ok to have the reactor on the line entity.
+                    h.get("text").update_on(
+                        broadcast::<RefreshChat>(),
+                        move |//
+                            id: TargetId,
+                            mut e: TextEditor,
+                            log: Res<ChatLog>,
+                            players: Query<(&PlayerId, &PlayerName)>,
+                            //
+                        |
+                        {
+                            let first = log.len().saturating_sub(CHAT_VISIBLE_MESSAGES);
+                            let Some(message) = log.get(first + line) else { return };
+                            let name = sender_name(message.from, &players);
+                            write_text!(e, *id, "{}: {}", name, message.text);
+                        },
+                    );
+                });
+
+                *num_lines += 1;
+            }
+        },
+    );
+
+    // Show the message being typed.
+    h.get("input::text").update_on(
+        broadcast::<RefreshChat>(),
+        |id: TargetId, mut e: TextEditor, draft: Res<ChatDraft>| {
+            if draft.typing {
+                write_text!(e, *id, "> {}_", draft.text);
+            } else {
+                write_text!(e, *id, "Press Enter to chat");
+            }
+        },
+    );
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(super) struct ChatPlugin;
+
+impl Plugin for ChatPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.init_resource::<ChatDraft>()
+            .add_systems(OnExit(ClientAppState::Game), reset_chat_draft)
+            .add_systems(
+                Update,
+                (
+                    edit_chat_draft.run_if(in_state(ClientState::Play).or(in_state(ClientState::Paused))),
+                    broadcast_system::<RefreshChat>
+                        .run_if(resource_changed::<ChatLog>.or(resource_changed::<ChatDraft>)),
+                )
+                    .chain()
+                    .in_set(ClientLogicSet::Update),
+            );
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
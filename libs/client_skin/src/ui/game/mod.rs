@@ -1,5 +1,9 @@
+mod chat;
 mod plugin;
 mod scoreboard;
+mod targets;
 
+pub(self) use chat::*;
 pub(super) use plugin::*;
 pub(self) use scoreboard::*;
+pub(self) use targets::*;
@@ -4,24 +4,70 @@ use bevy_cobweb_ui::prelude::*;
 use bevy_girk_client_fw::ClientAppState;
 use bevy_girk_utils::Sender;
 use bevy_renet2::prelude::RenetClient;
-use client_core::ClientState;
-use game_core::PlayerInput;
+use client_core::{ClientLogicSet, ClientState, Overtime};
+use game_core::{default_player_name, GameState, PlayerId, PlayerInput, PlayerName};
 use wiring_game_instance::{ClientContext, ClientType};
 
 use super::*;
 use crate::*;
 
+/// Marks the root of the game UI.
+#[derive(Component)]
+struct GameUi;
+
+#[derive(Default)]
+struct RefreshOvertime;
+
+#[derive(Default)]
+struct RefreshPlayerNames;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// The game UI is kept when the game is paused, so it only needs to be built once.
+fn game_ui_missing(ui: Query<(), With<GameUi>>) -> bool
+{
+    ui.is_empty()
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn player_renamed(players: Query<(), Changed<PlayerName>>) -> bool
+{
+    !players.is_empty()
+}
+
 //-------------------------------------------------------------------------------------------------------------------
 
 fn edit_header(mut h: UiSceneHandle)
 {
-    h.get("name_shim::name")
-        .update(|id: TargetId, mut e: TextEditor, context: Res<ClientContext>| {
+    h.get("name_shim::name").update_on(
+        broadcast::<RefreshPlayerNames>(),
+        |id: TargetId, mut e: TextEditor, context: Res<ClientContext>, players: Query<(&PlayerId, &PlayerName)>| {
             match context.client_type() {
-                ClientType::Player => write_text!(e, *id, "player{}", context.id()),
+                ClientType::Player => {
+                    let name = players
+                        .iter()
+                        .find(|(player_id, _)| player_id.id == context.id())
+                        .map(|(_, name)| name.name.clone())
+                        .unwrap_or_else(|| default_player_name(context.id()));
+                    write_text!(e, *id, "{}", name)
+                }
                 ClientType::Watcher => write_text!(e, *id, "watcher{}", context.id()),
             };
-        });
+        },
+    );
+    edit_countdown_text(h.get("clock::text"), GameState::Play, |secs| {
+        format!("{}:{:02}", secs / 60, secs % 60)
+    });
+    h.get("overtime::text").update_on(
+        broadcast::<RefreshOvertime>(),
+        |id: TargetId, mut e: TextEditor, overtime: Res<Overtime>| {
+            match overtime.is_active() {
+                true => write_text!(e, *id, "OVERTIME"),
+                false => write_text!(e, *id, ""),
+            };
+        },
+    );
     h.get("fps::text").update_on(
         resource_mutation::<FpsTracker>(),
         |id: TargetId, mut next_time: Local<u64>, mut e: TextEditor, fps: ReactRes<FpsTracker>| {
@@ -41,10 +87,23 @@ fn edit_header(mut h: UiSceneHandle)
 
 fn edit_content(mut h: UiSceneHandle)
 {
+    edit_team_scoreboard(h.get("scoreboard_shim::team_scores"));
     edit_scoreboard(h.get("scoreboard_shim::scoreboard"));
+    edit_chat(h.get("chat_panel"));
+
+    // Targets are spawned here in target games.
+    h.get("button_area").insert(TargetArea);
 
     // Clicker. This is how you 'play' the demo.
+    // - target games are played by clicking targets instead
     h.get("button_area::click_button")
+        .update(|id: TargetId, mut c: Commands, context: Res<ClientContext>| {
+            if !context.game_mode().has_targets() {
+                return;
+            }
+            let Ok(mut ec) = c.get_entity(*id) else { return };
+            ec.apply(DisplayControl::Hide);
+        })
         .on_pressed(|player_input: Res<Sender<PlayerInput>>| {
             let _ = player_input.send(PlayerInput::ClickButton);
         });
@@ -54,6 +113,9 @@ fn edit_content(mut h: UiSceneHandle)
 
 fn edit_footer(mut h: UiSceneHandle)
 {
+    // Pause button. Pauses may need a vote depending on the game's pause config.
+    edit_pause_button(h.get("pause_button"));
+
     // Disconnect button. Lets you test in-game disconnects.
     h.get("disconnect_button")
         .update(
@@ -74,7 +136,7 @@ fn build_ui(mut c: Commands, mut s: SceneBuilder)
 {
     let scene = ("ui.skin.game", "game");
     c.ui_root().spawn_scene(scene, &mut s, |h| {
-        h.insert(StateScoped(ClientAppState::Game));
+        h.insert((GameUi, StateScoped(ClientAppState::Game)));
 
         edit_header(h.get("header"));
         edit_content(h.get("content"));
@@ -91,7 +153,20 @@ impl Plugin for GameUiPlugin
     fn build(&self, app: &mut App)
     {
         app.add_plugins(ScoreboardPlugin)
-            .add_systems(OnEnter(ClientState::Play), build_ui.after(RefreshScoreboardSet));
+            .add_plugins(ChatPlugin)
+            .add_plugins(TargetsPlugin)
+            .add_systems(
+                OnEnter(ClientState::Play),
+                build_ui.after(RefreshScoreboardSet).run_if(game_ui_missing),
+            )
+            .add_systems(
+                Update,
+                (
+                    broadcast_system::<RefreshOvertime>.run_if(resource_changed::<Overtime>),
+                    broadcast_system::<RefreshPlayerNames>.run_if(player_renamed),
+                )
+                    .in_set(ClientLogicSet::End),
+            );
     }
 }
 
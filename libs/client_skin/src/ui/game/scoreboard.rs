@@ -8,16 +8,19 @@ use game_core::*;
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Player scores, ordered from highest to lowest.
+///
+/// Scores are `None` if they are hidden. Players with hidden scores are listed last.
 #[derive(ReactResource, Default)]
 struct Scoreboard
 {
-    entries: HashMap<Entity, PlayerScore>,
-    ordered: BTreeSet<(PlayerScore, Entity)>,
+    entries: HashMap<Entity, Option<u32>>,
+    ordered: BTreeSet<(Option<u32>, Entity)>,
 }
 
 impl Scoreboard
 {
-    fn update(&mut self, player: Entity, new_score: PlayerScore)
+    fn update(&mut self, player: Entity, new_score: Option<u32>)
     {
         let score = self.entries.entry(player).or_default();
         let prev_score = *score;
@@ -27,12 +30,12 @@ impl Scoreboard
         self.ordered.insert((new_score, player));
     }
 
-    fn get(&self, idx: usize) -> Result<Entity, ()>
+    fn get(&self, idx: usize) -> Result<(Entity, Option<u32>), ()>
     {
         self.ordered
             .iter()
             .nth(self.ordered.len().saturating_sub(idx + 1))
-            .map(|(_, e)| *e)
+            .map(|(score, e)| (*e, *score))
             .ok_or(())
     }
 
@@ -44,31 +47,149 @@ impl Scoreboard
 
 //-------------------------------------------------------------------------------------------------------------------
 
-fn refresh_scoreboard(mut c: Commands, players: Query<(Entity, &PlayerScore)>)
+/// Team scores, ordered from highest to lowest.
+#[derive(ReactResource, Default)]
+struct TeamScoreboard
+{
+    teams: Vec<TeamScore>,
+}
+
+impl TeamScoreboard
+{
+    fn update(&mut self, new_score: TeamScore)
+    {
+        self.teams.retain(|score| score.team != new_score.team);
+        self.teams.push(new_score);
+        self.teams
+            .sort_by(|a, b| b.score.cmp(&a.score).then(a.team.cmp(&b.team)));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Gets the score to display for a player.
+///
+/// The local player's score includes their predicted clicks so it updates as soon as they click.
+fn displayed_score(player: Entity, score: Option<&PlayerScore>, prediction: &PredictedScore) -> Option<u32>
+{
+    match prediction.player() == Some(player) {
+        true => Some(prediction.score()),
+        false => score.map(PlayerScore::score),
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn refresh_scoreboard(
+    mut c: Commands,
+    prediction: Res<PredictedScore>,
+    players: Query<(Entity, Option<&PlayerScore>), With<PlayerId>>,
+)
 {
     let mut scoreboard = Scoreboard::default();
     for (player, score) in players.iter() {
-        scoreboard.update(player, *score);
+        scoreboard.update(player, displayed_score(player, score, &prediction));
+    }
+    c.insert_react_resource(scoreboard);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn refresh_team_scoreboard(mut c: Commands, teams: Query<&TeamScore>)
+{
+    let mut scoreboard = TeamScoreboard::default();
+    for score in teams.iter() {
+        scoreboard.update(*score);
     }
     c.insert_react_resource(scoreboard);
 }
 
 //-------------------------------------------------------------------------------------------------------------------
 
+fn get_team_score_changes(
+    mut c: Commands,
+    mut scoreboard: ReactResMut<TeamScoreboard>,
+    teams: Query<&TeamScore, Changed<TeamScore>>,
+)
+{
+    if teams.is_empty() {
+        return;
+    }
+
+    let scoreboard = scoreboard.get_mut(&mut c);
+    for score in teams.iter() {
+        scoreboard.update(*score);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 fn get_score_changes(
     mut c: Commands,
     mut scoreboard: ReactResMut<Scoreboard>,
-    players: Query<(Entity, &PlayerScore), Changed<PlayerScore>>,
+    prediction: Res<PredictedScore>,
+    changed: Query<(Entity, Option<&PlayerScore>), (With<PlayerId>, Or<(Changed<PlayerScore>, Added<PlayerId>)>)>,
+    players: Query<&PlayerScore>,
 )
 {
-    if players.is_empty() {
+    if changed.is_empty() && !prediction.is_changed() {
         return;
     }
 
     let scoreboard = scoreboard.get_mut(&mut c);
-    for (player, score) in players.iter() {
-        scoreboard.update(player, *score);
+    for (player, score) in changed.iter() {
+        scoreboard.update(player, displayed_score(player, score, &prediction));
     }
+    if let Some(player) = prediction.player() {
+        if let Ok(score) = players.get(player) {
+            scoreboard.update(player, displayed_score(player, Some(score), &prediction));
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Refreshes the scoreboard when players disconnect, reconnect, forfeit, or change their name.
+fn get_label_changes(
+    mut c: Commands,
+    mut scoreboard: ReactResMut<Scoreboard>,
+    players: Query<(), Or<(Changed<PlayerConnection>, Changed<PlayerName>)>>,
+)
+{
+    if players.is_empty() {
+        return;
+    }
+
+    scoreboard.get_mut(&mut c);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Gets the scoreboard label for a player.
+///
+/// Handicaps are shown as points per click (e.g. `x2`) and click cooldown in ticks (e.g. `cd 3`).
+fn player_label(name: &PlayerName, connection: &PlayerConnection, handicap: &PlayerHandicap) -> String
+{
+    let mut label = name.name.clone();
+
+    if !handicap.is_none() {
+        let mut parts = Vec::new();
+        if handicap.points_per_click != 1 {
+            parts.push(format!("x{}", handicap.points_per_click));
+        }
+        if handicap.click_cooldown_ticks > 0 {
+            parts.push(format!("cd {}", handicap.click_cooldown_ticks));
+        }
+        label.push_str(&format!(" [{}]", parts.join(", ")));
+    }
+
+    match connection {
+        PlayerConnection::Connected => (),
+        PlayerConnection::Disconnected { .. } => label.push_str(" (disconnected)"),
+        PlayerConnection::Forfeited => label.push_str(" (forfeited)"),
+    }
+
+    label
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -120,13 +241,17 @@ pub(super) fn edit_scoreboard(mut h: UiSceneHandle)
                         _: TargetId,
                         mut e: TextEditor,
                         scoreboard: ReactRes<Scoreboard>,
-                        players: Query<(&PlayerName, &PlayerScore)>
+                        players: Query<(&PlayerName, &PlayerConnection, &PlayerHandicap)>
                         //
                     |
                     {
-                        let (name, score) = players.get(scoreboard.get(idx)?)?;
-                        write_text!(e, player_text, "{}", name.name.as_str());
-                        write_text!(e, score_text, "{}", score.score());
+                        let (player, score) = scoreboard.get(idx)?;
+                        let (name, connection, handicap) = players.get(player)?;
+                        write_text!(e, player_text, "{}", player_label(name, connection, handicap));
+                        match score {
+                            Some(score) => write_text!(e, score_text, "{}", score),
+                            None => write_text!(e, score_text, "?"),
+                        };
                         DONE
                     }
                 );
@@ -139,6 +264,39 @@ pub(super) fn edit_scoreboard(mut h: UiSceneHandle)
 
 //-------------------------------------------------------------------------------------------------------------------
 
+pub(super) fn edit_team_scoreboard(mut h: UiSceneHandle)
+{
+    h.update_on(
+        resource_mutation::<TeamScoreboard>(),
+        |//
+            id: TargetId,
+            mut c: Commands,
+            mut e: TextEditor,
+            scoreboard: ReactRes<TeamScoreboard>,
+            //
+        |
+        {
+            // only show team scores in team games
+            let Ok(mut ec) = c.get_entity(*id) else { return };
+            if scoreboard.teams.is_empty() {
+                ec.apply(DisplayControl::Hide);
+                return;
+            }
+            ec.apply(DisplayControl::Show);
+
+            let text = scoreboard
+                .teams
+                .iter()
+                .map(|score| format!("Team {}: {}", score.team + 1, score.score))
+                .collect::<Vec<_>>()
+                .join("   ");
+            write_text!(e, *id, "{}", text);
+        },
+    );
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 #[derive(SystemSet, PartialEq, Eq, Debug, Hash, Clone)]
 pub(super) struct RefreshScoreboardSet;
 
@@ -151,13 +309,14 @@ impl Plugin for ScoreboardPlugin
     fn build(&self, app: &mut App)
     {
         app.init_react_resource::<Scoreboard>()
+            .init_react_resource::<TeamScoreboard>()
             .add_systems(
                 OnEnter(ClientState::Play),
-                refresh_scoreboard.in_set(RefreshScoreboardSet),
+                (refresh_scoreboard, refresh_team_scoreboard).in_set(RefreshScoreboardSet),
             )
             .add_systems(
                 Update,
-                get_score_changes
+                (get_score_changes, get_label_changes, get_team_score_changes)
                     .in_set(ClientLogicSet::Update)
                     .run_if(not(in_state(ClientState::Init))),
             );
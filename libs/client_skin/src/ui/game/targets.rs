@@ -0,0 +1,110 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use bevy_cobweb_ui::prelude::*;
+use bevy_girk_client_fw::ClientAppState;
+use bevy_girk_utils::Sender;
+use client_core::*;
+use game_core::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Width and height of target buttons.
+const TARGET_SIZE_PX: f32 = 50.0;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Marks the UI node that target buttons are spawned in.
+#[derive(Component)]
+pub(super) struct TargetArea;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// [ replicated target entity : target button entity ]
+#[derive(Resource, Default)]
+struct TargetButtons(HashMap<Entity, Entity>);
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Spawns a button for each new target.
+fn spawn_target_buttons(
+    mut c: Commands,
+    mut s: SceneBuilder,
+    mut buttons: ResMut<TargetButtons>,
+    area: Query<Entity, With<TargetArea>>,
+    targets: Query<(Entity, &Target), Added<Target>>,
+)
+{
+    let Ok(area) = area.single() else { return };
+
+    for (target_entity, target) in targets.iter() {
+        let target_id = target.id;
+
+        // keep targets inside the area
+        let node = AbsoluteNode {
+            left: Val::Percent(target.x * 90.0),
+            top: Val::Percent(target.y * 90.0),
+            width: Val::Px(TARGET_SIZE_PX),
+            height: Val::Px(TARGET_SIZE_PX),
+            ..default()
+        };
+
+        c.ui_builder(area)
+            .spawn_scene(("ui.skin.game", "target"), &mut s, |h| {
+                buttons.0.insert(target_entity, h.id());
+
+                h.update(move |id: TargetId, mut c: Commands| {
+                    let Ok(mut ec) = c.get_entity(*id) else { return };
+                    ec.apply(node.clone());
+                })
+                .on_pressed(move |player_input: Res<Sender<PlayerInput>>| {
+                    let _ = player_input.send(PlayerInput::ClickTarget { target_id });
+                });
+            });
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Despawns the buttons of targets that were clicked or expired.
+fn despawn_target_buttons(
+    mut c: Commands,
+    mut removed: RemovedComponents<Target>,
+    mut buttons: ResMut<TargetButtons>,
+)
+{
+    for target_entity in removed.read() {
+        let Some(button) = buttons.0.remove(&target_entity) else { continue };
+        let Ok(mut ec) = c.get_entity(button) else { continue };
+        ec.despawn();
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn reset_target_buttons(mut buttons: ResMut<TargetButtons>)
+{
+    *buttons = TargetButtons::default();
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(super) struct TargetsPlugin;
+
+impl Plugin for TargetsPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.init_resource::<TargetButtons>()
+            .add_systems(OnExit(ClientAppState::Game), reset_target_buttons)
+            .add_systems(
+                Update,
+                (despawn_target_buttons, spawn_target_buttons)
+                    .chain()
+                    .in_set(ClientLogicSet::Update)
+                    .run_if(not(in_state(ClientState::Init))),
+            );
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -1,9 +1,15 @@
+mod countdown;
 mod game;
 mod game_over;
 mod loadscreen;
+mod pause;
 mod plugin;
+mod rejection_toast;
 
+pub(crate) use countdown::*;
 pub(self) use game::*;
 pub(self) use game_over::*;
 pub(self) use loadscreen::*;
+pub(crate) use pause::*;
 pub(crate) use plugin::*;
+pub(self) use rejection_toast::*;
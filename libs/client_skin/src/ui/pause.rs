@@ -0,0 +1,84 @@
+use bevy::prelude::*;
+use bevy_cobweb::prelude::*;
+use bevy_cobweb_ui::prelude::*;
+use client_core::*;
+use wiring_game_instance::{ClientContext, ClientType};
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Default)]
+pub(crate) struct RefreshPauseVotes;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Disables a button for non-player clients, who can't pause or resume the game.
+fn disable_for_watchers(id: TargetId, mut c: Commands, ps: PseudoStateParam, context: Res<ClientContext>)
+{
+    if context.client_type() != ClientType::Player {
+        ps.try_disable(&mut c, *id);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Sets up a button that asks the game to pause. The button shows ongoing pause votes.
+pub(crate) fn edit_pause_button(mut h: UiSceneHandle)
+{
+    h.update(disable_for_watchers).on_pressed(request_pause);
+    h.get("text").update_on(
+        broadcast::<RefreshPauseVotes>(),
+        |id: TargetId, mut e: TextEditor, votes: Res<PauseVotes>| {
+            if votes.pause_votes() > 0 && votes.votes_needed() > 1 {
+                write_text!(e, *id, "Pause ({}/{})", votes.pause_votes(), votes.votes_needed());
+            } else {
+                write_text!(e, *id, "Pause");
+            }
+        },
+    );
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn add_pause_overlay(mut c: Commands, mut s: SceneBuilder)
+{
+    let scene = ("ui.skin", "pause_overlay");
+    c.ui_root().spawn_scene(scene, &mut s, |h| {
+        h.insert(StateScoped(ClientState::Paused));
+
+        h.get("votes").update_on(
+            broadcast::<RefreshPauseVotes>(),
+            |id: TargetId, mut e: TextEditor, votes: Res<PauseVotes>| {
+                if votes.votes_needed() > 1 {
+                    write_text!(e, *id, "Votes to resume: {}/{}", votes.resume_votes(), votes.votes_needed());
+                } else {
+                    write_text!(e, *id, "");
+                }
+            },
+        );
+        h.get("resume_button")
+            .update(disable_for_watchers)
+            .on_pressed(request_resume);
+    });
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(super) struct PauseOverlayPlugin;
+
+impl Plugin for PauseOverlayPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.add_systems(OnEnter(ClientState::Paused), add_pause_overlay)
+            .add_systems(
+                Update,
+                broadcast_system::<RefreshPauseVotes>
+                    .in_set(ClientLogicSet::End)
+                    .run_if(resource_changed::<PauseVotes>),
+            );
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -14,8 +14,11 @@ impl Plugin for UiPlugin
         app
             .load("client_skin/main.cob")
             .add_plugins(LoadScreenPlugin)
+            .add_plugins(CountdownPlugin)
+            .add_plugins(PauseOverlayPlugin)
             .add_plugins(GameUiPlugin)
             .add_plugins(GameOverPlugin)
+            .add_plugins(RejectionToastPlugin)
             //.add_plugins(UiDebugOverlayPlugin)  //DEBUG ONLY
             ;
     }
@@ -0,0 +1,96 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_cobweb::prelude::*;
+use bevy_cobweb_ui::prelude::*;
+use bevy_girk_client_fw::ClientAppState;
+use client_core::*;
+use game_core::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// How long a rejection toast stays on screen.
+const TOAST_DURATION: Duration = Duration::from_secs(2);
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Explains why a request was refused.
+fn rejection_message(request: &ClientRequest, reason: RejectionReason) -> Option<&'static str>
+{
+    let message = match (reason, request) {
+        (RejectionReason::RateLimited, _) => "Slow down, you're clicking too fast!",
+        (RejectionReason::PauseLimitReached, _) => "You have no pauses left.",
+        (RejectionReason::MessageTooLong, _) => "Your chat message is too long.",
+        (RejectionReason::InvalidName, _) => "Your player name isn't allowed.",
+        (RejectionReason::NameTaken, _) => "That name is already taken.",
+        (RejectionReason::ModeMismatch, ClientRequest::PlayerInput(_)) => "You can't click right now.",
+        (RejectionReason::ModeMismatch, ClientRequest::RequestPause) => "The game can't be paused right now.",
+        (RejectionReason::ModeMismatch, ClientRequest::RequestResume) => "The game isn't paused.",
+        (RejectionReason::ModeMismatch, ClientRequest::SetPlayerName { .. }) => "Names can't be changed now.",
+        (RejectionReason::ModeMismatch, _) => "That can't be done right now.",
+        (RejectionReason::Invalid, ClientRequest::PlayerInput(_)) => "That click didn't count.",
+        (RejectionReason::Invalid, _) => "That request isn't allowed.",
+        (RejectionReason::None, _) => return None,
+    };
+    Some(message)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Shows a toast explaining a rejected request. Only the latest toast is shown.
+fn show_rejection_toast(
+    event: BroadcastEvent<RequestRejected>,
+    mut c: Commands,
+    mut s: SceneBuilder,
+    time: Res<Time>,
+    toasts: Query<Entity, With<RejectionToast>>,
+)
+{
+    let rejected = event.read();
+    let Some(message) = rejection_message(&rejected.request, rejected.reason) else { return };
+
+    for toast in toasts.iter() {
+        c.entity(toast).despawn();
+    }
+
+    let expires_at = time.elapsed() + TOAST_DURATION;
+    let scene = ("ui.skin", "rejection_toast");
+    c.ui_root().spawn_scene(scene, &mut s, |h| {
+        h.insert((RejectionToast { expires_at }, StateScoped(ClientAppState::Game)));
+        h.get("text").update_text(message);
+    });
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn expire_rejection_toasts(mut c: Commands, time: Res<Time>, toasts: Query<(Entity, &RejectionToast)>)
+{
+    for (entity, toast) in toasts.iter() {
+        if time.elapsed() >= toast.expires_at {
+            c.entity(entity).despawn();
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Component, Debug)]
+struct RejectionToast
+{
+    expires_at: Duration,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(super) struct RejectionToastPlugin;
+
+impl Plugin for RejectionToastPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.add_reactor(broadcast::<RequestRejected>(), show_rejection_toast)
+            .add_systems(Update, expire_rejection_toasts.in_set(ClientLogicSet::End));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,154 @@
+use bevy::prelude::*;
+use bevy_cobweb::prelude::*;
+use bevy_girk_utils::*;
+use serde::{Deserialize, Serialize};
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Domain separator for bot PRNGs.
+const BOT_RAND_DOMAIN: &str = "click_bot";
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Collects bots that should click this tick and schedules their next click.
+fn collect_bot_clicks(
+    game_ctx: Res<ClickGameContext>,
+    game_tick: Res<GameTick>,
+    mut bots: Query<(&PlayerId, &mut PlayerBot), Without<PlayerEliminated>>,
+) -> Vec<ClientId>
+{
+    let tick = ***game_tick;
+    let ticks_per_sec = game_ctx.ticks_per_sec();
+
+    let mut clickers: Vec<ClientId> = bots
+        .iter_mut()
+        .filter_map(|(player_id, mut bot)| {
+            if tick < bot.next_click_tick {
+                return None;
+            }
+            bot.schedule_next_click(tick, ticks_per_sec);
+            Some(player_id.id)
+        })
+        .collect();
+
+    // bots click in client id order so the same bot claims contested targets in replays
+    clickers.sort_unstable();
+    clickers
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Simulates clicks for bot players.
+///
+/// Runs after targets are expired and spawned for the tick, so bots only see live targets.
+///
+/// Bot clicks go through the same click handling as client requests, but are not recorded in replays since they
+/// are reproduced by the bots' deterministic PRNGs.
+fn simulate_bot_clicks(world: &mut World)
+{
+    let clickers = world.syscall((), collect_bot_clicks);
+    let has_targets = world.resource::<ClickGameContext>().game_mode().has_targets();
+
+    for client_id in clickers {
+        let Ok(player_entity) = world.resource::<PlayerMap>().client_to_entity(client_id) else {
+            tracing::error!(client_id, "bot is not a player");
+            continue;
+        };
+
+        // bots go for the oldest target
+        let input = match has_targets {
+            true => {
+                let Some(target_id) = world.syscall((), get_oldest_target) else { continue };
+                PlayerInput::ClickTarget { target_id }
+            }
+            false => PlayerInput::ClickButton,
+        };
+        world.syscall((player_entity, input), handle_player_input);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Skill level of a bot player.
+#[derive(Eq, PartialEq, Hash, Copy, Clone, Debug, Serialize, Deserialize)]
+pub enum BotDifficulty
+{
+    Easy,
+    Medium,
+    Hard,
+}
+
+impl BotDifficulty
+{
+    /// Average number of clicks per second.
+    pub fn clicks_per_sec(&self) -> f32
+    {
+        match self {
+            Self::Easy => 3.0,
+            Self::Medium => 5.0,
+            Self::Hard => 8.0,
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Component for players simulated by the game instead of a client.
+///
+/// This component is not replicated.
+#[derive(Component)]
+pub struct PlayerBot
+{
+    difficulty: BotDifficulty,
+    rand: Rand64,
+    next_click_tick: u32,
+}
+
+impl PlayerBot
+{
+    pub fn new(difficulty: BotDifficulty, seed: u64, player_id: PlayerId) -> Self
+    {
+        Self {
+            difficulty,
+            rand: make_player_rand(BOT_RAND_DOMAIN, seed, player_id),
+            next_click_tick: 0,
+        }
+    }
+
+    pub fn difficulty(&self) -> BotDifficulty
+    {
+        self.difficulty
+    }
+
+    /// Picks the next click time with +/-40% jitter around the difficulty's average click interval.
+    fn schedule_next_click(&mut self, tick: u32, ticks_per_sec: u32)
+    {
+        let mean_interval = ticks_per_sec as f32 / self.difficulty.clicks_per_sec();
+        let jitter = 0.6 + 0.8 * (self.rand.next() as f64 / u64::MAX as f64) as f32;
+        let interval = ((mean_interval * jitter).round() as u32).max(1);
+        self.next_click_tick = tick + interval;
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Bot plugin.
+pub(crate) struct GameBotPlugin;
+
+impl Plugin for GameBotPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.add_systems(
+            Update,
+            simulate_bot_clicks
+                .after(spawn_targets)
+                .in_set(GameSet::Play)
+                .in_set(GameLogicSet::Update),
+        );
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
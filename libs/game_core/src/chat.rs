@@ -0,0 +1,104 @@
+use std::collections::{HashMap, VecDeque};
+
+use bevy::prelude::*;
+use bevy_girk_game_fw::*;
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Handles a chat message sent by a player or watcher.
+///
+/// Messages are rejected if they are empty, too long, or over the client's rate limit. Watcher messages are only
+/// sent to other watchers unless [`ChatConfig::watchers_visible_to_players`] is set.
+pub(crate) fn handle_chat_request(
+    In((client_id, text)): In<(ClientId, String)>,
+    mut sender: GameSender,
+    game_ctx: Res<ClickGameContext>,
+    game_tick: Res<GameTick>,
+    watchers: Res<WatcherMap>,
+    mut limiter: ResMut<ChatLimiter>,
+)
+{
+    let config = game_ctx.chat_config();
+    let message = text.trim();
+
+    // validate the message
+    let rejection = if message.is_empty() {
+        Some(RejectionReason::Invalid)
+    } else if message.chars().count() > config.max_message_chars() as usize {
+        Some(RejectionReason::MessageTooLong)
+    } else if !limiter.try_record(client_id, ***game_tick, config) {
+        Some(RejectionReason::RateLimited)
+    } else {
+        None
+    };
+    if let Some(reason) = rejection {
+        tracing::debug!(client_id, ?reason, "rejected chat message");
+        let request = ClientRequest::Chat { text };
+        sender.send_to_client(GameMsg::RequestRejected { reason, request }, client_id);
+        return;
+    }
+
+    // watchers may only be allowed to talk to each other
+    if watchers.is_watcher(client_id) && !config.watchers_visible_to_players() {
+        for watcher_id in watchers.iter() {
+            sender.send_to_client(GameMsg::Chat { from: client_id, text: message.into() }, watcher_id);
+        }
+        return;
+    }
+
+    sender.send_to_all(GameMsg::Chat { from: client_id, text: message.into() });
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Server-side record of recent chat messages from each client, used for rate limiting.
+#[derive(Resource, Default, Debug)]
+pub(crate) struct ChatLimiter
+{
+    /// [ client id : game ticks of recent messages ]
+    recent: HashMap<ClientId, VecDeque<u32>>,
+}
+
+impl ChatLimiter
+{
+    /// Records a message at `tick`.
+    ///
+    /// Returns `false` if the message exceeds the rate limit, in which case it is not recorded.
+    fn try_record(&mut self, client_id: ClientId, tick: u32, config: &ChatConfig) -> bool
+    {
+        let recent = self.recent.entry(client_id).or_default();
+
+        // drop messages that fell out of the window
+        while let Some(&oldest) = recent.front() {
+            if tick.saturating_sub(oldest) < config.window_ticks() {
+                break;
+            }
+            recent.pop_front();
+        }
+
+        // rate limit
+        if recent.len() >= config.max_messages_per_window() as usize {
+            return false;
+        }
+        recent.push_back(tick);
+
+        true
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Chat plugin.
+pub(crate) struct GameChatPlugin;
+
+impl Plugin for GameChatPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.init_resource::<ChatLimiter>();
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
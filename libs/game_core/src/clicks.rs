@@ -0,0 +1,78 @@
+use bevy::prelude::*;
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Adds accepted clicks to the player's score.
+///
+/// Each click is worth [`PlayerHandicap::points_per_click`].
+fn score_click(trigger: Trigger<PlayerClicked>, mut players: Query<(&mut PlayerScore, &PlayerHandicap)>)
+{
+    let PlayerClicked { player, client_id } = *trigger.event();
+    let Ok((mut score, handicap)) = players.get_mut(player) else {
+        tracing::error!(client_id, "score click: unknown player entity");
+        return;
+    };
+
+    score.add(handicap.points_per_click);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Adds accepted clicks to the player's [`ClickTracker`], which rate limits clicks and flags automated clicking.
+fn track_click_rate(
+    trigger: Trigger<PlayerClicked>,
+    game_ctx: Res<ClickGameContext>,
+    game_tick: Res<GameTick>,
+    mut players: Query<&mut ClickTracker>,
+)
+{
+    let PlayerClicked { player, client_id } = *trigger.event();
+    let Ok(mut tracker) = players.get_mut(player) else {
+        tracing::error!(client_id, "track click rate: unknown player entity");
+        return;
+    };
+
+    let was_flagged = tracker.is_flagged();
+    tracker.record(***game_tick, game_ctx.click_limit_config());
+    if !was_flagged && tracker.is_flagged() {
+        tracing::warn!(client_id, "player flagged for automated clicking");
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Adds accepted clicks to the player's click statistics.
+fn record_click_timeline(
+    trigger: Trigger<PlayerClicked>,
+    game_ctx: Res<ClickGameContext>,
+    play_tick: Res<PlayTick>,
+    mut players: Query<&mut ClickTimeline>,
+)
+{
+    let PlayerClicked { player, client_id } = *trigger.event();
+    let Ok(mut timeline) = players.get_mut(player) else {
+        tracing::error!(client_id, "record click timeline: unknown player entity");
+        return;
+    };
+
+    timeline.record(***play_tick, game_ctx.ticks_per_sec());
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Click scoring, anti-cheat, and statistics plugin.
+pub(crate) struct GameClickPlugin;
+
+impl Plugin for GameClickPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.add_observer(score_click)
+            .add_observer(track_click_rate)
+            .add_observer(record_click_timeline);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
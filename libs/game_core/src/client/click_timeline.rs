@@ -0,0 +1,37 @@
+use bevy::prelude::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Server-side record of when a player's clicks were accepted during [`GameState::Play`](crate::GameState::Play).
+///
+/// Clicks are bucketed by [`PlayTick`](crate::PlayTick) into one-second buckets. This component is not replicated.
+#[derive(Component, Default, Debug)]
+pub struct ClickTimeline
+{
+    /// Number of clicks in each one-second bucket.
+    buckets: Vec<u32>,
+}
+
+impl ClickTimeline
+{
+    pub(crate) fn record(&mut self, play_tick: u32, ticks_per_sec: u32)
+    {
+        let bucket = (play_tick / ticks_per_sec.max(1)) as usize;
+        if self.buckets.len() <= bucket {
+            self.buckets.resize(bucket + 1, 0);
+        }
+        self.buckets[bucket] += 1;
+    }
+
+    /// Gets the timeline padded with empty buckets up to `num_buckets`.
+    pub fn padded(&self, num_buckets: usize) -> Vec<u32>
+    {
+        let mut buckets = self.buckets.clone();
+        if buckets.len() < num_buckets {
+            buckets.resize(num_buckets, 0);
+        }
+        buckets
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,100 @@
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Server-side record of a player's recent clicks, used for rate limiting and bot detection.
+///
+/// This component is not replicated.
+#[derive(Component, Default, Debug)]
+pub struct ClickTracker
+{
+    /// Game ticks of accepted clicks within the current rate-limit window.
+    window: VecDeque<u32>,
+    /// Tick of the last accepted click.
+    last_click: Option<u32>,
+    /// Most recent non-zero intervals between accepted clicks.
+    intervals: VecDeque<u32>,
+    /// Set if the player's click pattern looked automated at any point in the game.
+    flagged: bool,
+}
+
+impl ClickTracker
+{
+    /// Returns `true` if a click at `tick` would exceed the rate limit.
+    ///
+    /// Note: This is only `pub` for testing purposes.
+    pub fn is_rate_limited(&mut self, tick: u32, config: &ClickLimitConfig) -> bool
+    {
+        // drop clicks that fell out of the window
+        while let Some(&oldest) = self.window.front() {
+            if tick.saturating_sub(oldest) < config.window_ticks() {
+                break;
+            }
+            self.window.pop_front();
+        }
+
+        self.window.len() >= config.max_clicks_per_window() as usize
+    }
+
+    /// Records an accepted click at `tick`.
+    ///
+    /// Note: This is only `pub` for testing purposes.
+    pub fn record(&mut self, tick: u32, config: &ClickLimitConfig)
+    {
+        self.window.push_back(tick);
+
+        // track intervals
+        // - clicks in the same tick can't be timed apart, so zero intervals aren't sampled
+        let interval = self
+            .last_click
+            .map(|last_click| tick.saturating_sub(last_click));
+        if let Some(interval) = interval.filter(|interval| *interval > 0) {
+            if self.intervals.len() >= config.interval_samples() as usize {
+                self.intervals.pop_front();
+            }
+            self.intervals.push_back(interval);
+        }
+        self.last_click = Some(tick);
+
+        // bot detection
+        if !self.flagged
+            && self.intervals.len() >= config.interval_samples() as usize
+            && self.interval_variance() < config.min_interval_variance()
+        {
+            self.flagged = true;
+        }
+    }
+
+    /// Returns `true` if a click at `tick` would be within `cooldown_ticks` of the last accepted click.
+    pub(crate) fn is_cooling_down(&self, tick: u32, cooldown_ticks: u32) -> bool
+    {
+        self.last_click
+            .is_some_and(|last_click| tick.saturating_sub(last_click) < cooldown_ticks)
+    }
+
+    /// Returns `true` if the player was flagged as a possible bot.
+    pub fn is_flagged(&self) -> bool
+    {
+        self.flagged
+    }
+
+    fn interval_variance(&self) -> f32
+    {
+        if self.intervals.is_empty() {
+            return 0.0;
+        }
+        let count = self.intervals.len() as f32;
+        let mean = self.intervals.iter().map(|i| *i as f32).sum::<f32>() / count;
+        self.intervals
+            .iter()
+            .map(|i| (*i as f32 - mean).powi(2))
+            .sum::<f32>()
+            / count
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
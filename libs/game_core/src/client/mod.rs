@@ -1,7 +1,11 @@
+mod click_timeline;
+mod click_tracker;
 mod player_map;
 mod player_state;
 mod watcher_map;
 
+pub use click_timeline::*;
+pub use click_tracker::*;
 pub use player_map::*;
 pub use player_state::*;
 pub use watcher_map::*;
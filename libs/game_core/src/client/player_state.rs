@@ -3,7 +3,7 @@ use bevy_replicon::prelude::*;
 use bevy_replicon_attributes::*;
 use serde::{Deserialize, Serialize};
 
-use crate::ClientId;
+use crate::*;
 
 //-------------------------------------------------------------------------------------------------------------------
 
@@ -25,7 +25,7 @@ pub struct PlayerName
 
 //-------------------------------------------------------------------------------------------------------------------
 
-#[derive(Component, Default, Serialize, Deserialize, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Debug)]
+#[derive(Component, Default, Serialize, Deserialize, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash, Debug)]
 pub struct PlayerScore
 {
     /// Note: This is only `pub` for testing purposes.
@@ -34,9 +34,9 @@ pub struct PlayerScore
 
 impl PlayerScore
 {
-    pub(crate) fn increment(&mut self)
+    pub(crate) fn add(&mut self, points: u32)
     {
-        self.score += 1;
+        self.score = self.score.saturating_add(points);
     }
     pub fn score(&self) -> u32
     {
@@ -46,6 +46,116 @@ impl PlayerScore
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Replicated copy of a player's [`PlayerScore`].
+///
+/// Scores are replicated on their own entities so they can be hidden from other players without hiding the rest of
+/// the player's state. Clients copy the score onto the player entity.
+#[derive(Component, Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Debug)]
+pub struct ReplicatedScore
+{
+    /// The player entity.
+    #[entities]
+    pub player: Entity,
+    pub score: PlayerScore,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Player handicap component, used to balance games between players of different skill.
+#[derive(Component, Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct PlayerHandicap
+{
+    /// Points added to the player's score for each accepted click.
+    pub points_per_click: u32,
+    /// Min number of game ticks between the player's accepted clicks.
+    pub click_cooldown_ticks: u32,
+}
+
+impl PlayerHandicap
+{
+    /// Returns `true` if the handicap doesn't change anything.
+    pub fn is_none(&self) -> bool
+    {
+        *self == Self::default()
+    }
+}
+
+impl Default for PlayerHandicap
+{
+    fn default() -> Self
+    {
+        Self { points_per_click: 1, click_cooldown_ticks: 0 }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Player team component.
+///
+/// Only added to players in team games.
+#[derive(Component, Copy, Clone, Eq, PartialEq, Hash, Debug, Serialize, Deserialize)]
+pub struct PlayerTeam
+{
+    /// Team index, starting at 0.
+    pub team: u8,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Marker component added to players knocked out in [`GameMode::Elimination`].
+#[derive(Component, Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct PlayerEliminated
+{
+    /// The elimination round the player was knocked out in (starting at 1).
+    pub round: u32,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Player connection status component.
+///
+/// Bots are always [`PlayerConnection::Connected`].
+#[derive(Component, Default, Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum PlayerConnection
+{
+    #[default]
+    Connected,
+    /// The player's client disconnected on game tick `since`. The player will forfeit if they don't reconnect
+    /// within the grace period in [`DisconnectConfig`].
+    Disconnected
+    {
+        since: u32,
+    },
+    /// The player was disconnected for longer than the grace period. Forfeited players can't score, even if they
+    /// reconnect.
+    Forfeited,
+}
+
+impl PlayerConnection
+{
+    pub fn is_connected(&self) -> bool
+    {
+        *self == Self::Connected
+    }
+
+    pub fn is_forfeited(&self) -> bool
+    {
+        *self == Self::Forfeited
+    }
+
+    /// Updates the status when the player's client connects or disconnects on game tick `tick`.
+    pub(crate) fn set_connected(&mut self, connected: bool, tick: u32)
+    {
+        match (*self, connected) {
+            (Self::Disconnected { .. }, true) => *self = Self::Connected,
+            (Self::Connected, false) => *self = Self::Disconnected { since: tick },
+            _ => (),
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 /// Players are entities with the components bundled here.
 #[derive(Bundle)]
 pub struct PlayerState
@@ -56,10 +166,17 @@ pub struct PlayerState
     pub name: PlayerName,
     /// Current player score
     pub score: PlayerScore,
-    /// Players are replicated
+    /// Player connection status.
+    pub connection: PlayerConnection,
+    /// Player handicap.
+    pub handicap: PlayerHandicap,
+    /// Players are replicated. The score is replicated separately with [`ReplicatedScore`].
     pub replicate: Replicated,
     /// Players have a visibility condition.
     pub visibility: VisibilityCondition,
+    /// Player team in team games. This is inserted separately when spawning the player.
+    #[bundle(ignore)]
+    pub team: Option<PlayerTeam>,
 }
 
 impl Default for PlayerState
@@ -70,8 +187,11 @@ impl Default for PlayerState
             id: PlayerId { id: 1 },
             name: Default::default(),
             score: Default::default(),
+            connection: Default::default(),
+            handicap: Default::default(),
             replicate: Default::default(),
             visibility: vis![Global],
+            team: None,
         }
     }
 }
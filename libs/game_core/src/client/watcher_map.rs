@@ -6,23 +6,45 @@ use crate::ClientId;
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Tracks watchers and replay viewers.
+///
+/// Replay viewers are treated as watchers, except they aren't counted by [`Self::len`].
 #[derive(Resource)]
 pub struct WatcherMap
 {
     /// [ client id  ]
     watchers: HashSet<ClientId>,
+    /// [ client id  ]
+    viewers: HashSet<ClientId>,
 }
 
 impl WatcherMap
 {
-    pub fn new(watchers: HashSet<ClientId>) -> WatcherMap
+    pub fn new(watchers: HashSet<ClientId>, viewers: HashSet<ClientId>) -> WatcherMap
     {
-        WatcherMap { watchers }
+        WatcherMap { watchers, viewers }
     }
 
     pub fn is_watcher(&self, client_id: ClientId) -> bool
     {
-        self.watchers.contains(&client_id)
+        self.watchers.contains(&client_id) || self.viewers.contains(&client_id)
+    }
+
+    /// Iterates watchers and replay viewers.
+    pub fn iter(&self) -> impl Iterator<Item = ClientId> + '_
+    {
+        self.watchers.iter().chain(self.viewers.iter()).copied()
+    }
+
+    /// Gets the number of watchers, not counting replay viewers.
+    pub fn len(&self) -> usize
+    {
+        self.watchers.len()
+    }
+
+    pub fn is_empty(&self) -> bool
+    {
+        self.watchers.is_empty()
     }
 }
 
@@ -10,6 +10,11 @@ pub enum PlayerInput
 {
     /// Click a button.
     ClickButton,
+    /// Click a [`Target`](crate::Target) in [`GameMode::Targets`](crate::GameMode::Targets).
+    ClickTarget
+    {
+        target_id: u32,
+    },
 }
 
 impl IntoChannel for PlayerInput
@@ -18,6 +23,7 @@ impl IntoChannel for PlayerInput
     {
         match &self {
             Self::ClickButton => SendUnordered.into(),
+            Self::ClickTarget { .. } => SendUnordered.into(),
         }
     }
 }
@@ -25,13 +31,38 @@ impl IntoChannel for PlayerInput
 //-------------------------------------------------------------------------------------------------------------------
 
 /// Requests that can be sent to the game.
-#[derive(Debug, Serialize, Deserialize, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub enum ClientRequest
 {
     /// Request the current game mode.
     GetGameState,
     /// Player input.
     PlayerInput(PlayerInput),
+    /// Ask to pause the game during [`GameState::Play`](crate::GameState::Play).
+    RequestPause,
+    /// Ask to resume the game during [`GameState::Paused`](crate::GameState::Paused).
+    RequestResume,
+    /// Send a chat message to other clients.
+    Chat
+    {
+        text: String,
+    },
+    /// Set the player's display name.
+    ///
+    /// Names are checked with [`validate_player_name`](crate::validate_player_name) and must be unique within
+    /// the game. Players can only change their names before [`GameState::Play`](crate::GameState::Play).
+    SetPlayerName
+    {
+        name: String,
+    },
+    /// Report that the client's replicated state doesn't match a
+    /// [`GameMsg::StateHash`](crate::GameMsg::StateHash).
+    ReportDesync
+    {
+        tick: u32,
+        expected: u64,
+        found: u64,
+    },
 }
 
 impl IntoChannel for ClientRequest
@@ -41,6 +72,11 @@ impl IntoChannel for ClientRequest
         match &self {
             Self::GetGameState => SendOrdered.into(),
             Self::PlayerInput(input) => input.into_event_type(),
+            Self::RequestPause => SendOrdered.into(),
+            Self::RequestResume => SendOrdered.into(),
+            Self::Chat { .. } => SendOrdered.into(),
+            Self::SetPlayerName { .. } => SendOrdered.into(),
+            Self::ReportDesync { .. } => SendUnordered.into(),
         }
     }
 }
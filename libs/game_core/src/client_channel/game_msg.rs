@@ -7,11 +7,20 @@ use crate::*;
 //-------------------------------------------------------------------------------------------------------------------
 
 /// Reasons a game request may be rejected
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Hash)]
 pub enum RejectionReason
 {
     ModeMismatch,
     Invalid,
+    RateLimited,
+    /// The player has used all their pauses.
+    PauseLimitReached,
+    /// The chat message is longer than the chat config allows.
+    MessageTooLong,
+    /// The player name is not allowed.
+    InvalidName,
+    /// The player name is used by another player.
+    NameTaken,
     None,
 }
 
@@ -27,6 +36,52 @@ pub enum GameMsg
         request: ClientRequest,
     },
     CurrentGameState(GameState),
+    /// Number of ticks remaining in the current [`GameState::Prep`] or [`GameState::Play`] phase.
+    ///
+    /// Sent when entering each phase and in response to [`ClientRequest::GetGameState`].
+    StateTimer
+    {
+        game_state: GameState,
+        remaining_ticks: u32,
+        /// Game tick rate, for converting ticks to real time.
+        ticks_per_sec: u32,
+    },
+    /// Current pause and resume votes.
+    ///
+    /// Sent when a player asks to pause or resume the game.
+    PauseVotes
+    {
+        pause_votes: u32,
+        resume_votes: u32,
+        /// Number of votes needed to pause or resume.
+        votes_needed: u32,
+    },
+    /// Sudden-death overtime started because `players` were tied for first.
+    ///
+    /// Sent when overtime starts and in response to [`ClientRequest::GetGameState`] during overtime. While scores
+    /// are hidden, players are only told if they are tied themselves, so `players` may be empty.
+    Overtime
+    {
+        players: Vec<ClientId>,
+        /// Max number of ticks remaining in overtime.
+        remaining_ticks: u32,
+        /// Game tick rate, for converting ticks to real time.
+        ticks_per_sec: u32,
+    },
+    /// Hash of the replicated player and team state visible to the client on game tick `tick`.
+    ///
+    /// Sent periodically so clients can detect desyncs. See [`ReplicatedPlayerState::hash`].
+    StateHash
+    {
+        tick: u32,
+        hash: u64,
+    },
+    /// A chat message from a player or watcher.
+    Chat
+    {
+        from: ClientId,
+        text: String,
+    },
 }
 
 impl IntoChannel for GameMsg
@@ -36,6 +91,11 @@ impl IntoChannel for GameMsg
         match &self {
             Self::RequestRejected { .. } => SendUnordered.into(),
             Self::CurrentGameState(_) => SendOrdered.into(),
+            Self::StateTimer { .. } => SendOrdered.into(),
+            Self::PauseVotes { .. } => SendOrdered.into(),
+            Self::Overtime { .. } => SendOrdered.into(),
+            Self::StateHash { .. } => SendOrdered.into(),
+            Self::Chat { .. } => SendOrdered.into(),
         }
     }
 }
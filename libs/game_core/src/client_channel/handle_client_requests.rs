@@ -27,7 +27,7 @@ where
 pub(crate) fn handle_client_request(world: &mut World, id: ClientId, req: ClientRequest)
 {
     let state = world.syscall((), get_game_state);
-    let reject = |world: &mut World| {
+    let reject = |world: &mut World, req: ClientRequest| {
         world.syscall((id, req, RejectionReason::ModeMismatch), notify_request_rejected);
     };
 
@@ -35,8 +35,28 @@ pub(crate) fn handle_client_request(world: &mut World, id: ClientId, req: Client
         ClientRequest::GetGameState => world.syscall(id, handle_game_state_request),
         ClientRequest::PlayerInput(i) => match state {
             GameState::Play => player_syscall(world, id, req, i, handle_player_input),
-            _ => reject(world),
+            _ => reject(world, req),
         },
+        ClientRequest::RequestPause => match state {
+            GameState::Play => player_syscall(world, id, req, (), handle_pause_request),
+            _ => reject(world, req),
+        },
+        ClientRequest::RequestResume => match state {
+            GameState::Paused => player_syscall(world, id, req, (), handle_resume_request),
+            _ => reject(world, req),
+        },
+        ClientRequest::SetPlayerName { ref name } => match state {
+            GameState::GameOver => reject(world, req),
+            _ => {
+                let name = name.clone();
+                player_syscall(world, id, req, name, handle_player_name_request);
+            }
+        },
+        // chat doesn't affect the game, so it isn't recorded
+        ClientRequest::Chat { text } => world.syscall((id, text), handle_chat_request),
+        ClientRequest::ReportDesync { tick, expected, found } => {
+            world.syscall((id, tick, expected, found), handle_desync_report)
+        }
     }
 }
 
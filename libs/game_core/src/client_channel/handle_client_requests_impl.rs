@@ -1,3 +1,4 @@
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 use bevy_cobweb::prelude::*;
 use bevy_girk_game_fw::*;
@@ -6,15 +7,95 @@ use crate::*;
 
 //-------------------------------------------------------------------------------------------------------------------
 
-//todo: consider converting this to an event, which can be responded to in a 'player click' plugin
-fn handle_player_click_button(In(player_entity): In<Entity>, mut players: Query<&mut PlayerScore, With<PlayerId>>)
+/// Game state that decides whether clicks are allowed.
+#[derive(SystemParam)]
+struct ClickConditions<'w>
 {
-    let Ok(mut player_score) = players.get_mut(player_entity) else {
-        tracing::error!("handle player click button: unknown player entity");
+    game_ctx: Res<'w, ClickGameContext>,
+    game_tick: Res<'w, GameTick>,
+    overtime: Res<'w, GameOvertime>,
+    hidden_scores: Option<Res<'w, HiddenScores>>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Validates a player's click and triggers [`PlayerClicked`] if it is accepted.
+///
+/// In [`GameMode::Targets`] only clicks on live targets are accepted, and the first player to click a target
+/// claims it.
+fn handle_player_click(
+    In((player_entity, input)): In<(Entity, PlayerInput)>,
+    mut c: Commands,
+    mut sender: GameSender,
+    conditions: ClickConditions,
+    mut players: Query<(
+        &PlayerId,
+        &mut ClickTracker,
+        &PlayerConnection,
+        &PlayerHandicap,
+        Has<PlayerEliminated>,
+        Has<PlayerBot>,
+    )>,
+    targets: Query<(Entity, &Target)>,
+)
+{
+    let Ok((player_id, mut tracker, connection, handicap, eliminated, is_bot)) = players.get_mut(player_entity)
+    else {
+        tracing::error!("handle player click: unknown player entity");
         return;
     };
+    let request = ClientRequest::PlayerInput(input);
+    let ClickConditions { game_ctx, game_tick, overtime, hidden_scores } = conditions;
 
-    player_score.increment();
+    // clicks must match the game mode, and targets must still be live
+    let target = match input {
+        PlayerInput::ClickButton => None,
+        PlayerInput::ClickTarget { target_id } => targets
+            .iter()
+            .find(|(_, target)| target.id == target_id)
+            .map(|(entity, _)| entity),
+    };
+    let wrong_input = match input {
+        PlayerInput::ClickButton => game_ctx.game_mode().has_targets(),
+        PlayerInput::ClickTarget { .. } => target.is_none(),
+    };
+
+    // only tied players can score in overtime
+    // - while scores are hidden, other players aren't told about overtime, so their clicks are rejected as if play
+    //   had ended
+    let overtime_blocked = overtime.is_started() && !overtime.can_score(player_id.id);
+    if overtime_blocked && hidden_scores.is_some() {
+        let reason = RejectionReason::ModeMismatch;
+        sender.send_to_client(GameMsg::RequestRejected { reason, request }, player_id.id);
+        return;
+    }
+
+    // eliminated and forfeited players can't score
+    if wrong_input || eliminated || connection.is_forfeited() || overtime_blocked {
+        let reason = RejectionReason::Invalid;
+        sender.send_to_client(GameMsg::RequestRejected { reason, request }, player_id.id);
+        return;
+    }
+
+    // reject clicks over the rate limit or during the player's handicap cooldown
+    // - accepted clicks are added to the tracker by a `PlayerClicked` observer
+    if tracker.is_cooling_down(***game_tick, handicap.click_cooldown_ticks)
+        || tracker.is_rate_limited(***game_tick, game_ctx.click_limit_config())
+    {
+        tracing::debug!(player_id.id, "rate limited player click");
+        let reason = RejectionReason::RateLimited;
+        sender.send_to_client(GameMsg::RequestRejected { reason, request }, player_id.id);
+        return;
+    }
+
+    if let Some(target) = target {
+        c.entity(target).despawn();
+    }
+    // bot clicks are reproduced by the bots' PRNGs, so they aren't reported as accepted requests
+    if !is_bot {
+        c.trigger(RequestAccepted { client_id: player_id.id, request });
+    }
+    c.trigger(PlayerClicked { player: player_entity, client_id: player_id.id });
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -32,6 +113,8 @@ pub(crate) fn notify_request_rejected(
 pub(crate) fn handle_game_state_request(In(client_id): In<ClientId>, world: &mut World)
 {
     world.syscall(client_id, notify_game_state_single);
+    world.syscall(client_id, notify_state_timer_single);
+    world.syscall(client_id, notify_overtime_single);
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -39,7 +122,9 @@ pub(crate) fn handle_game_state_request(In(client_id): In<ClientId>, world: &mut
 pub(crate) fn handle_player_input(In((player_entity, input)): In<(Entity, PlayerInput)>, world: &mut World)
 {
     match input {
-        PlayerInput::ClickButton => world.syscall(player_entity, handle_player_click_button),
+        PlayerInput::ClickButton | PlayerInput::ClickTarget { .. } => {
+            world.syscall((player_entity, input), handle_player_click)
+        }
     }
 }
 
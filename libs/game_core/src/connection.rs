@@ -0,0 +1,120 @@
+use std::collections::HashSet;
+
+use bevy::prelude::*;
+use bevy_cobweb::prelude::*;
+use bevy_girk_game_fw::*;
+use bevy_replicon::prelude::*;
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Detects players connecting and disconnecting from the game server.
+///
+/// All players are assumed to be connected when the game leaves initialization. Bots are always connected.
+fn track_player_connections(
+    mut c: Commands,
+    mut connected: Local<Option<HashSet<ClientId>>>,
+    clients: Query<&NetworkId, With<ConnectedClient>>,
+    players: Query<&PlayerId, Without<PlayerBot>>,
+)
+{
+    let current: HashSet<ClientId> = clients.iter().map(|network_id| network_id.get()).collect();
+    let prev = connected.get_or_insert_with(|| players.iter().map(|player_id| player_id.id).collect());
+
+    for player_id in players.iter() {
+        let is_connected = current.contains(&player_id.id);
+        if prev.contains(&player_id.id) == is_connected {
+            continue;
+        }
+        c.syscall((player_id.id, is_connected), apply_connection_change);
+    }
+
+    *prev = players
+        .iter()
+        .map(|player_id| player_id.id)
+        .filter(|id| current.contains(id))
+        .collect();
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Forfeits players who have been disconnected for longer than the grace period.
+pub(crate) fn forfeit_disconnected_players(
+    mut c: Commands,
+    game_ctx: Res<ClickGameContext>,
+    game_tick: Res<GameTick>,
+    mut players: Query<(Entity, &PlayerId, &mut PlayerConnection)>,
+)
+{
+    let grace_ticks = game_ctx.disconnect_config().grace_ticks();
+
+    for (player, player_id, mut connection) in players.iter_mut() {
+        let PlayerConnection::Disconnected { since } = *connection else { continue };
+        if (***game_tick).saturating_sub(since) < grace_ticks {
+            continue;
+        }
+        *connection = PlayerConnection::Forfeited;
+        c.trigger(PlayerForfeited { player, client_id: player_id.id });
+        tracing::info!(player_id.id, "player forfeited after disconnecting");
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Updates a player's [`PlayerConnection`] when their client connects or disconnects.
+///
+/// Changes are recorded in the [`ReplayRecorder`].
+pub(crate) fn apply_connection_change(
+    In((client_id, connected)): In<(ClientId, bool)>,
+    game_tick: Res<GameTick>,
+    player_map: Res<PlayerMap>,
+    mut players: Query<&mut PlayerConnection>,
+    mut recorder: ResMut<ReplayRecorder>,
+)
+{
+    let Some(mut connection) = player_map
+        .client_to_entity(client_id)
+        .ok()
+        .and_then(|player_entity| players.get_mut(player_entity).ok())
+    else {
+        tracing::error!(client_id, "connection change: unknown player");
+        return;
+    };
+
+    let mut new_connection = *connection;
+    new_connection.set_connected(connected, ***game_tick);
+    if new_connection == *connection {
+        return;
+    }
+    *connection = new_connection;
+    recorder.record_connection(**game_tick, client_id, connected);
+    tracing::info!(client_id, ?new_connection, "player connection changed");
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Player connection plugin.
+///
+/// Connections are tracked while the game is running. When playing back a replay, the recorded connection changes
+/// are applied instead (see [`add_replay_connection_changes`]).
+pub(crate) struct GameConnectionPlugin;
+
+impl Plugin for GameConnectionPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.add_systems(
+            Update,
+            (
+                track_player_connections.run_if(not(resource_exists::<ReplayConnectionChanges>)),
+                forfeit_disconnected_players.run_if(not(in_state(GameState::GameOver))),
+            )
+                .chain()
+                .in_set(GameSet::PostInit)
+                .before(GameLogicSet::Admin),
+        );
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
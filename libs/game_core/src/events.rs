@@ -0,0 +1,40 @@
+use bevy::prelude::*;
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Triggered when a client request that affects the game is accepted.
+///
+/// Requests are validated before this is triggered, so rejected requests never appear here.
+#[derive(Event, Debug, Clone)]
+pub struct RequestAccepted
+{
+    pub client_id: ClientId,
+    pub request: ClientRequest,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Triggered when a player's click is accepted by the game.
+///
+/// Clicks from eliminated or forfeited players, and clicks over the rate limit, are rejected before this is
+/// triggered.
+#[derive(Event, Debug, Copy, Clone)]
+pub struct PlayerClicked
+{
+    pub player: Entity,
+    pub client_id: ClientId,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Triggered when a player forfeits by staying disconnected for longer than the grace period.
+#[derive(Event, Debug, Copy, Clone)]
+pub struct PlayerForfeited
+{
+    pub player: Entity,
+    pub client_id: ClientId,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -1,20 +1,48 @@
+mod bots;
+mod chat;
 mod client;
+mod clicks;
 mod client_channel;
+mod connection;
+mod events;
 mod game_rand;
 mod meta;
+mod modes;
+mod overtime;
+mod pause;
+mod player_names;
 mod plugin;
+mod replay;
 mod sets;
 mod setup;
+mod state_hash;
 mod states;
+mod targets;
+mod teams;
 mod ticks;
+mod visibility;
 
+pub use bots::*;
+pub use chat::*;
 pub use client::*;
+pub use clicks::*;
 pub use client_channel::*;
+pub use connection::*;
+pub use events::*;
 pub use game_rand::*;
 pub use meta::*;
+pub use modes::*;
+pub use overtime::*;
+pub use pause::*;
+pub use player_names::*;
 pub use plugin::*;
 pub use renet2::ClientId;
+pub use replay::*;
 pub use sets::*;
 pub(crate) use setup::*;
+pub use state_hash::*;
 pub use states::*;
+pub use targets::*;
+pub use teams::*;
 pub use ticks::*;
+pub use visibility::*;
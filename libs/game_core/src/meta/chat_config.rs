@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Configuration for in-game chat.
+#[derive(Eq, PartialEq, Hash, Copy, Clone, Debug, Serialize, Deserialize)]
+pub struct ChatConfig
+{
+    /// Max number of characters in a chat message. Longer messages are rejected.
+    max_message_chars: u32,
+    /// Length of the sliding window used for rate limiting, in game ticks.
+    window_ticks: u32,
+    /// Max number of messages a client may send within [`Self::window_ticks`].
+    max_messages_per_window: u32,
+    /// If `false`, watcher messages are only sent to other watchers.
+    ///
+    /// Watchers can see every player's screen, so this stops them from feeding information to players.
+    watchers_visible_to_players: bool,
+}
+
+impl ChatConfig
+{
+    pub fn new(
+        max_message_chars: u32,
+        window_ticks: u32,
+        max_messages_per_window: u32,
+        watchers_visible_to_players: bool,
+    ) -> ChatConfig
+    {
+        ChatConfig {
+            max_message_chars,
+            window_ticks: window_ticks.max(1),
+            max_messages_per_window,
+            watchers_visible_to_players,
+        }
+    }
+
+    pub fn max_message_chars(&self) -> u32
+    {
+        self.max_message_chars
+    }
+    pub fn window_ticks(&self) -> u32
+    {
+        self.window_ticks
+    }
+    pub fn max_messages_per_window(&self) -> u32
+    {
+        self.max_messages_per_window
+    }
+    pub fn watchers_visible_to_players(&self) -> bool
+    {
+        self.watchers_visible_to_players
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize};
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Configuration for server-side click rate limiting and bot detection.
+#[derive(PartialEq, Copy, Clone, Debug, Serialize, Deserialize)]
+pub struct ClickLimitConfig
+{
+    /// Length of the sliding window used for rate limiting, in game ticks.
+    window_ticks: u32,
+    /// Max number of clicks a player may make within [`Self::window_ticks`]. Clicks over the limit are rejected.
+    max_clicks_per_window: u32,
+    /// Number of recent click intervals sampled for bot detection.
+    ///
+    /// Only non-zero intervals are sampled, and players aren't judged until this many have been sampled.
+    interval_samples: u32,
+    /// Players whose sampled click intervals have a variance below this value (in ticks²) are flagged as bots.
+    ///
+    /// Human clicking is irregular, so a near-constant interval between clicks is a strong hint of automation.
+    min_interval_variance: f32,
+}
+
+impl ClickLimitConfig
+{
+    pub fn new(
+        window_ticks: u32,
+        max_clicks_per_window: u32,
+        interval_samples: u32,
+        min_interval_variance: f32,
+    ) -> ClickLimitConfig
+    {
+        ClickLimitConfig {
+            window_ticks: window_ticks.max(1),
+            max_clicks_per_window,
+            interval_samples: interval_samples.max(2),
+            min_interval_variance,
+        }
+    }
+
+    pub fn window_ticks(&self) -> u32
+    {
+        self.window_ticks
+    }
+    pub fn max_clicks_per_window(&self) -> u32
+    {
+        self.max_clicks_per_window
+    }
+    pub fn interval_samples(&self) -> u32
+    {
+        self.interval_samples
+    }
+    pub fn min_interval_variance(&self) -> f32
+    {
+        self.min_interval_variance
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Configuration for handling players that disconnect mid-game.
+#[derive(Eq, PartialEq, Hash, Copy, Clone, Debug, Serialize, Deserialize)]
+pub struct DisconnectConfig
+{
+    /// Number of game ticks a player may stay disconnected before they forfeit.
+    grace_ticks: u32,
+}
+
+impl DisconnectConfig
+{
+    pub fn new(grace_ticks: u32) -> DisconnectConfig
+    {
+        DisconnectConfig { grace_ticks }
+    }
+
+    pub fn grace_ticks(&self) -> u32
+    {
+        self.grace_ticks
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -1,34 +1,65 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
 use crate::*;
 
 //-------------------------------------------------------------------------------------------------------------------
 
 /// Static information in a game app.
-#[derive(Resource)]
+#[derive(Resource, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ClickGameContext
 {
     /// Seed for the game's deterministic random number generator.
     seed: u128,
-    /// Game duration config.
-    duration_config: GameDurationConfig,
+    /// Game framework tick rate.
+    ticks_per_sec: u32,
+    /// Game rules.
+    rules: ClickGameRules,
 }
 
 impl ClickGameContext
 {
     /// New game context
-    pub fn new(seed: u128, duration_config: GameDurationConfig) -> ClickGameContext
+    pub fn new(seed: u128, ticks_per_sec: u32, rules: ClickGameRules) -> ClickGameContext
     {
-        ClickGameContext { seed, duration_config }
+        ClickGameContext { seed, ticks_per_sec, rules }
     }
 
     pub fn seed(&self) -> u128
     {
         self.seed
     }
+    pub fn ticks_per_sec(&self) -> u32
+    {
+        self.ticks_per_sec
+    }
+    pub fn rules(&self) -> &ClickGameRules
+    {
+        &self.rules
+    }
     pub fn duration_config(&self) -> &GameDurationConfig
     {
-        &self.duration_config
+        &self.rules.duration_config
+    }
+    pub fn game_mode(&self) -> GameMode
+    {
+        self.rules.game_mode
+    }
+    pub fn click_limit_config(&self) -> &ClickLimitConfig
+    {
+        &self.rules.click_limit_config
+    }
+    pub fn pause_config(&self) -> &PauseConfig
+    {
+        &self.rules.pause_config
+    }
+    pub fn chat_config(&self) -> &ChatConfig
+    {
+        &self.rules.chat_config
+    }
+    pub fn disconnect_config(&self) -> &DisconnectConfig
+    {
+        &self.rules.disconnect_config
     }
 }
 
@@ -15,25 +15,89 @@ pub struct GameDurationConfig
     /// Number of ticks that should elapse in [GameState::Play] before switching [GameState::Play] ->
     /// [GameState::GameOver].
     game_ticks: u32,
-    // The first 'game over' tick will occur after 'prep_ticks + game_ticks' ticks have elapsed.
+    /// Max number of ticks of sudden-death overtime after [GameState::Play] ends with players tied for first.
+    /// Overtime is disabled if this is `0`.
+    overtime_ticks: u32,
+    // The first 'game over' tick will occur after 'prep_ticks + game_ticks' unpaused ticks have elapsed.
 }
 
 impl GameDurationConfig
 {
     pub fn new(prep_ticks: u32, game_ticks: u32) -> GameDurationConfig
     {
-        GameDurationConfig { prep_ticks, game_ticks }
+        GameDurationConfig { prep_ticks, game_ticks, overtime_ticks: 0 }
     }
 
-    pub fn expected_state(&self, game_tick: Tick) -> GameState
+    /// Enables sudden-death overtime lasting up to `overtime_ticks`.
+    pub fn with_overtime(mut self, overtime_ticks: u32) -> GameDurationConfig
     {
+        self.overtime_ticks = overtime_ticks;
+        self
+    }
+
+    /// Number of ticks from the start of [GameState::Prep] until [GameState::GameOver].
+    pub fn total_ticks(&self) -> u32
+    {
+        self.prep_ticks + self.game_ticks
+    }
+
+    pub fn prep_ticks(&self) -> u32
+    {
+        self.prep_ticks
+    }
+
+    pub fn game_ticks(&self) -> u32
+    {
+        self.game_ticks
+    }
+
+    pub fn overtime_ticks(&self) -> u32
+    {
+        self.overtime_ticks
+    }
+
+    /// Number of ticks remaining in the state expected at `game_tick`, after `paused_ticks` have elapsed in
+    /// [GameState::Paused].
+    ///
+    /// Returns `None` if the expected state is [GameState::GameOver].
+    pub fn remaining_ticks(&self, game_tick: Tick, paused_ticks: u32) -> Option<u32>
+    {
+        let elapsed = game_tick.saturating_sub(paused_ticks);
+        match self.expected_state(game_tick, paused_ticks) {
+            GameState::Prep => Some(self.prep_ticks - elapsed),
+            GameState::Play => Some(self.total_ticks() - elapsed),
+            _ => None,
+        }
+    }
+
+    /// Number of overtime ticks remaining at `game_tick`, after `paused_ticks` have elapsed in [GameState::Paused].
+    ///
+    /// Returns `None` if `game_tick` is not within the overtime window that follows [GameState::Play].
+    pub fn remaining_overtime_ticks(&self, game_tick: Tick, paused_ticks: u32) -> Option<u32>
+    {
+        let elapsed = game_tick.saturating_sub(paused_ticks);
+        let overtime_end = self.total_ticks() + self.overtime_ticks;
+        if elapsed < self.total_ticks() || elapsed >= overtime_end {
+            return None;
+        }
+        Some(overtime_end - elapsed)
+    }
+
+    /// Gets the state expected at `game_tick`, after `paused_ticks` have elapsed in [GameState::Paused].
+    ///
+    /// Paused ticks don't count toward the game duration. This never returns [GameState::Paused], since pausing
+    /// is not controlled by the duration config.
+    pub fn expected_state(&self, game_tick: Tick, paused_ticks: u32) -> GameState
+    {
+        let elapsed = game_tick.saturating_sub(paused_ticks);
+
         // prep
-        if *game_tick < self.prep_ticks {
+        if elapsed < self.prep_ticks {
             return GameState::Prep;
         }
 
         // play
-        if *game_tick < (self.prep_ticks + self.game_ticks) {
+        if elapsed < (self.prep_ticks + self.game_ticks) {
             return GameState::Play;
         }
 
@@ -16,8 +16,19 @@ pub struct ClickGameInitializer
     pub game_context: ClickGameContext,
     /// Player states.
     pub players: HashMap<ClientId, PlayerState>,
+    /// Players that are simulated by the game instead of a client.
+    pub bots: HashMap<ClientId, BotDifficulty>,
+    /// The lobby owner, if they are a player.
+    pub owner: Option<ClientId>,
     /// Watchers.
     pub watchers: HashSet<ClientId>,
+    /// Clients viewing a replay of the game.
+    ///
+    /// Viewers see the game like watchers, but they aren't part of the recorded game so they aren't counted in
+    /// the game over report.
+    pub viewers: HashSet<ClientId>,
+    /// If set, players can only see their own score until the game ends. Watchers can see all scores.
+    pub hidden_scores: bool,
 }
 
 //-------------------------------------------------------------------------------------------------------------------
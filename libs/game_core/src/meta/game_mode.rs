@@ -0,0 +1,62 @@
+use serde::{Deserialize, Serialize};
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Rule set used to decide when [`GameState::Play`](crate::GameState::Play) ends.
+///
+/// All modes are bounded by the play ticks in [`GameDurationConfig`](crate::GameDurationConfig), so a game will
+/// always end even if the mode's win condition is never reached.
+#[derive(Eq, PartialEq, Hash, Copy, Clone, Debug, Default, Serialize, Deserialize)]
+pub enum GameMode
+{
+    /// Play ends when the play ticks in the duration config have elapsed.
+    #[default]
+    Timed,
+    /// Play ends as soon as a player reaches `target_clicks`.
+    FirstTo
+    {
+        target_clicks: u32,
+    },
+    /// Play is split into rounds of `round_ticks`. At the end of each round the remaining players who scored the
+    /// least in that round are eliminated. Play ends when at most one player remains.
+    Elimination
+    {
+        round_ticks: u32,
+    },
+    /// Play ends when the play ticks in the duration config have elapsed. A target spawns at a random position
+    /// every `spawn_interval_ticks` and expires after `lifetime_ticks`. Only clicks on live targets score.
+    Targets
+    {
+        spawn_interval_ticks: u32,
+        lifetime_ticks: u32,
+    },
+}
+
+impl GameMode
+{
+    /// Get the click target if this is [`GameMode::FirstTo`].
+    pub fn target_clicks(&self) -> Option<u32>
+    {
+        match self {
+            Self::FirstTo { target_clicks } => Some(*target_clicks),
+            _ => None,
+        }
+    }
+
+    /// Get the round length if this is [`GameMode::Elimination`].
+    pub fn round_ticks(&self) -> Option<u32>
+    {
+        match self {
+            Self::Elimination { round_ticks } => Some(*round_ticks),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if this is [`GameMode::Targets`].
+    pub fn has_targets(&self) -> bool
+    {
+        matches!(self, Self::Targets { .. })
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
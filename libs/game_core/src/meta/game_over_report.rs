@@ -5,27 +5,80 @@ use crate::*;
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Ranks scores from highest to lowest, starting at 1.
+///
+/// Tied scores share a rank, and the next rank is skipped for each extra tied score (e.g. `1, 1, 3`).
+pub fn competition_ranks<T: PartialOrd>(scores: &[T]) -> Vec<u32>
+{
+    scores
+        .iter()
+        .map(|score| 1 + scores.iter().filter(|other| *other > score).count() as u32)
+        .collect()
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 /// Player report for the game over report.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ClickPlayerReport
 {
     /// Client id within the game.
     pub client_id: ClientId,
+    /// Player name when the game ended.
+    pub name: String,
     /// Player score during the game.
     pub score: PlayerScore,
+    /// Player team in team games.
+    pub team: Option<u8>,
+    /// Player connection status when the game ended.
+    pub connection: PlayerConnection,
+    /// Final ranking, starting at 1.
+    ///
+    /// Tied players share a rank, and the next rank is skipped for each extra tied player (e.g. `1, 1, 3`).
+    pub rank: u32,
+    /// Number of clicks in each second of [`GameState::Play`].
+    pub click_timeline: Vec<u32>,
+    /// Most clicks in a single second.
+    pub peak_clicks_per_sec: u32,
+    /// Average clicks per second over [`GameState::Play`].
+    pub average_clicks_per_sec: f32,
+    /// Set if the player's click pattern was flagged as automated.
+    pub flagged: bool,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Team report for the game over report.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ClickTeamReport
+{
+    /// Team index, starting at 0.
+    pub team: u8,
+    /// Sum of the scores of all players on the team.
+    pub score: u32,
+    /// Final team ranking, starting at 1. Tied teams share a rank.
+    pub rank: u32,
 }
 
 //-------------------------------------------------------------------------------------------------------------------
 
 /// Report emitted at the end of a game.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ClickGameOverReport
 {
     /// The last game tick that elapsed before this report was created.
     pub final_game_tick: Tick,
+    /// Number of ticks that elapsed in [`GameState::Play`].
+    pub play_ticks: Tick,
+    /// Number of watchers in the game.
+    pub watcher_count: u32,
+    /// The player who won in sudden-death overtime, if the game was decided in overtime.
+    pub overtime_winner: Option<ClientId>,
 
-    /// Each player's individual report.
+    /// Each player's individual report, sorted by client id.
     pub player_reports: Vec<ClickPlayerReport>,
+    /// Each team's report, sorted by team. Empty if the game doesn't have teams.
+    pub team_reports: Vec<ClickTeamReport>,
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Rules of a game, fixed when the game is made.
+#[derive(PartialEq, Copy, Clone, Debug, Serialize, Deserialize)]
+pub struct ClickGameRules
+{
+    /// Game duration config.
+    pub duration_config: GameDurationConfig,
+    /// Rule set that decides when the game ends.
+    pub game_mode: GameMode,
+    /// Click rate limiting and bot detection config.
+    pub click_limit_config: ClickLimitConfig,
+    /// Pause rules.
+    pub pause_config: PauseConfig,
+    /// Chat limits and visibility.
+    pub chat_config: ChatConfig,
+    /// Disconnect grace period.
+    pub disconnect_config: DisconnectConfig,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
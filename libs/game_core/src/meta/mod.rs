@@ -1,11 +1,23 @@
+mod chat_config;
+mod click_limit_config;
+mod disconnect_config;
 mod game_context;
 mod game_duration_config;
 mod game_initializer;
+mod game_mode;
 mod game_over_report;
+mod game_rules;
+mod pause_config;
 mod replication;
 
+pub use chat_config::*;
+pub use click_limit_config::*;
+pub use disconnect_config::*;
 pub use game_context::*;
 pub use game_duration_config::*;
 pub use game_initializer::*;
+pub use game_mode::*;
 pub use game_over_report::*;
+pub use game_rules::*;
+pub use pause_config::*;
 pub use replication::*;
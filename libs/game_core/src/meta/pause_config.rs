@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize};
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Decides who may pause and resume a game.
+#[derive(Eq, PartialEq, Hash, Copy, Clone, Debug, Default, Serialize, Deserialize)]
+pub enum PauseMode
+{
+    /// Pause requests are rejected.
+    #[default]
+    Disabled,
+    /// Only the lobby owner can pause and resume.
+    OwnerOnly,
+    /// The game pauses or resumes when a majority of human players have requested it.
+    Vote,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Configuration for pausing [`GameState::Play`](crate::GameState::Play).
+#[derive(Eq, PartialEq, Hash, Copy, Clone, Debug, Default, Serialize, Deserialize)]
+pub struct PauseConfig
+{
+    /// Who may pause and resume.
+    mode: PauseMode,
+    /// Max number of pauses each player can start (or vote for, if the vote succeeds).
+    max_pauses_per_player: u8,
+    /// Max length of a pause, in game ticks. Paused games resume automatically after this many ticks.
+    max_pause_ticks: u32,
+}
+
+impl PauseConfig
+{
+    pub fn new(mode: PauseMode, max_pauses_per_player: u8, max_pause_ticks: u32) -> PauseConfig
+    {
+        PauseConfig { mode, max_pauses_per_player, max_pause_ticks: max_pause_ticks.max(1) }
+    }
+
+    pub fn mode(&self) -> PauseMode
+    {
+        self.mode
+    }
+    pub fn max_pauses_per_player(&self) -> u8
+    {
+        self.max_pauses_per_player
+    }
+    pub fn max_pause_ticks(&self) -> u32
+    {
+        self.max_pause_ticks
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -16,7 +16,14 @@ impl Plugin for GameReplicationPlugin
     {
         app.replicate::<PlayerId>()
             .replicate::<PlayerName>()
-            .replicate::<PlayerScore>();
+            .replicate::<ReplicatedScore>()
+            .replicate::<PlayerConnection>()
+            .replicate::<PlayerHandicap>()
+            .replicate::<PlayerEliminated>()
+            .replicate::<PlayerTeam>()
+            .replicate::<TeamScore>()
+            .replicate::<Target>()
+            .replicate::<StateTick>();
     }
 }
 
@@ -0,0 +1,152 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Ends play when a player reaches the click target in [`GameMode::FirstTo`].
+fn check_first_to(
+    game_ctx: Res<ClickGameContext>,
+    players: Query<&PlayerScore, With<PlayerId>>,
+    mut finished: ResMut<GameModeFinished>,
+)
+{
+    let Some(target_clicks) = game_ctx.game_mode().target_clicks() else { return };
+    if finished.0 {
+        return;
+    }
+
+    if !players.iter().any(|score| score.score() >= target_clicks) {
+        return;
+    }
+    finished.0 = true;
+    tracing::info!(target_clicks, "a player reached the click target");
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Cumulative player scores at the start of the current elimination round.
+///
+/// Players without an entry had no score when the round started.
+#[derive(Resource, Default, Debug)]
+struct RoundStartScores(HashMap<Entity, u32>);
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Eliminates the remaining players with the lowest score in each round of [`GameMode::Elimination`].
+///
+/// If every remaining player is tied for the lowest round score, the players with the lowest cumulative score are
+/// eliminated instead. If they are tied too, one of them is eliminated at random so every round makes progress.
+fn run_elimination_round(
+    mut c: Commands,
+    game_ctx: Res<ClickGameContext>,
+    play_tick: Res<PlayTick>,
+    mut rand: ResMut<GameRand>,
+    mut round_start: ResMut<RoundStartScores>,
+    players: Query<(Entity, &PlayerId, &PlayerScore), Without<PlayerEliminated>>,
+    mut finished: ResMut<GameModeFinished>,
+)
+{
+    let Some(round_ticks) = game_ctx.game_mode().round_ticks() else { return };
+    if finished.0 {
+        return;
+    }
+
+    // only runs on round boundaries
+    let play_tick = ***play_tick;
+    if round_ticks == 0 || play_tick == 0 || (play_tick % round_ticks) != 0 {
+        return;
+    }
+    let round = play_tick / round_ticks;
+
+    // collect round and cumulative scores of remaining players
+    // - sorted by player id so tie breaks don't depend on query order
+    let mut remaining: Vec<(Entity, PlayerId, u32, u32)> = players
+        .iter()
+        .map(|(entity, id, score)| {
+            let start = round_start.0.get(&entity).copied().unwrap_or_default();
+            (entity, *id, score.score().saturating_sub(start), score.score())
+        })
+        .collect();
+    remaining.sort_unstable_by_key(|(_, id, ..)| id.id);
+    if remaining.is_empty() {
+        finished.0 = true;
+        return;
+    }
+    let num_remaining = remaining.len();
+
+    // find the players with the lowest round score
+    let lowest_round = remaining
+        .iter()
+        .map(|(_, _, round_score, _)| *round_score)
+        .min()
+        .unwrap_or_default();
+    let mut eliminated: Vec<_> = remaining
+        .iter()
+        .filter(|(_, _, round_score, _)| *round_score == lowest_round)
+        .collect();
+
+    // break ties between all remaining players
+    if eliminated.len() == num_remaining {
+        let lowest_total = eliminated
+            .iter()
+            .map(|(.., total)| *total)
+            .min()
+            .unwrap_or_default();
+        eliminated.retain(|(.., total)| *total == lowest_total);
+    }
+    if eliminated.len() == num_remaining {
+        let index = (rand.next() % eliminated.len() as u64) as usize;
+        eliminated = vec![eliminated[index]];
+    }
+
+    for (player_entity, ..) in eliminated.iter() {
+        c.entity(*player_entity).insert(PlayerEliminated { round });
+    }
+    let num_eliminated = eliminated.len();
+    tracing::info!(round, num_eliminated, "elimination round finished");
+
+    // the next round starts from the current scores
+    round_start.0 = remaining
+        .iter()
+        .map(|(entity, .., total)| (*entity, *total))
+        .collect();
+
+    // end the game when at most one player is left
+    if num_remaining - num_eliminated <= 1 {
+        finished.0 = true;
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Set when the active [`GameMode`] has reached its win condition.
+///
+/// Checked by the game state update to end [`GameState::Play`] early.
+#[derive(Resource, Default, Debug, Copy, Clone, Deref)]
+pub struct GameModeFinished(bool);
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Game mode plugin.
+pub(crate) struct GameModePlugin;
+
+impl Plugin for GameModePlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.init_resource::<GameModeFinished>()
+            .init_resource::<RoundStartScores>()
+            .add_systems(
+                Update,
+                (check_first_to, run_elimination_round)
+                    .chain()
+                    .in_set(GameSet::Play)
+                    .in_set(GameLogicSet::Update),
+            );
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,218 @@
+use std::collections::BTreeSet;
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy_girk_game_fw::*;
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Starts sudden-death overtime if play is ending with two or more players tied for first.
+pub(crate) fn start_overtime(
+    mode_finished: Res<GameModeFinished>,
+    msgs: OvertimeMsgParam,
+    players: Query<(&PlayerId, &PlayerScore), Without<PlayerEliminated>>,
+    clients: Query<&PlayerId, Without<PlayerBot>>,
+    mut overtime: ResMut<GameOvertime>,
+    mut sender: GameSender,
+)
+{
+    let duration_config = msgs.game_ctx.duration_config();
+    if duration_config.overtime_ticks() == 0 || overtime.started || **mode_finished {
+        return;
+    }
+    if duration_config.expected_state(**msgs.game_tick, msgs.pause.paused_ticks()) != GameState::GameOver {
+        return;
+    }
+
+    // find players tied for first
+    let Some(top_score) = players.iter().map(|(_, score)| *score).max() else { return };
+    let tied: BTreeSet<ClientId> = players
+        .iter()
+        .filter(|(_, score)| **score == top_score)
+        .map(|(player_id, _)| player_id.id)
+        .collect();
+    if tied.len() < 2 {
+        return;
+    }
+
+    overtime.started = true;
+    overtime.players = tied;
+    tracing::info!(players = ?overtime.players, "starting overtime");
+
+    msgs.send_to_all(&overtime, &clients, &mut sender);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// The first tied player to click in overtime wins.
+fn claim_overtime_win(trigger: Trigger<PlayerClicked>, mut overtime: ResMut<GameOvertime>)
+{
+    let client_id = trigger.event().client_id;
+    if !overtime.can_score(client_id) {
+        return;
+    }
+    overtime.winner = Some(client_id);
+    tracing::info!(client_id, "player won in overtime");
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Game state for making overtime messages.
+#[derive(SystemParam)]
+pub(crate) struct OvertimeMsgParam<'w>
+{
+    game_ctx: Res<'w, ClickGameContext>,
+    game_tick: Res<'w, GameTick>,
+    pause: Res<'w, GamePause>,
+    hidden_scores: Option<Res<'w, HiddenScores>>,
+    watchers: Res<'w, WatcherMap>,
+}
+
+impl OvertimeMsgParam<'_>
+{
+    /// Makes an overtime message for a client, or for all clients if `client_id` is `None`.
+    ///
+    /// While scores are hidden, only tied players are told about overtime (and only about themselves) so nobody
+    /// can learn who is leading. Watchers see every tied player.
+    ///
+    /// Returns `None` if overtime is not in progress or the client shouldn't be told about it.
+    fn make_msg(&self, overtime: &GameOvertime, client_id: Option<ClientId>) -> Option<GameMsg>
+    {
+        if !overtime.in_progress() {
+            return None;
+        }
+        let remaining_ticks = self
+            .game_ctx
+            .duration_config()
+            .remaining_overtime_ticks(**self.game_tick, self.pause.paused_ticks())?;
+
+        let tied = overtime.players.iter().copied();
+        let players = match client_id {
+            Some(client_id) if self.hidden_scores.is_some() && !self.watchers.is_watcher(client_id) => {
+                if !overtime.players.contains(&client_id) {
+                    return None;
+                }
+                vec![client_id]
+            }
+            _ => tied.collect(),
+        };
+
+        Some(GameMsg::Overtime {
+            players,
+            remaining_ticks,
+            ticks_per_sec: self.game_ctx.ticks_per_sec(),
+        })
+    }
+
+    fn send_to_all(
+        &self,
+        overtime: &GameOvertime,
+        clients: &Query<&PlayerId, Without<PlayerBot>>,
+        sender: &mut GameSender,
+    )
+    {
+        if self.hidden_scores.is_none() {
+            let Some(msg) = self.make_msg(overtime, None) else { return };
+            sender.send_to_all(msg);
+            return;
+        }
+
+        for client_id in clients
+            .iter()
+            .map(|player_id| player_id.id)
+            .chain(self.watchers.iter())
+        {
+            let Some(msg) = self.make_msg(overtime, Some(client_id)) else { continue };
+            sender.send_to_client(msg, client_id);
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Notify all clients of the current overtime, if overtime is in progress.
+fn notify_overtime_all(
+    msgs: OvertimeMsgParam,
+    overtime: Res<GameOvertime>,
+    clients: Query<&PlayerId, Without<PlayerBot>>,
+    mut sender: GameSender,
+)
+{
+    msgs.send_to_all(&overtime, &clients, &mut sender);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Notify a single client of the current overtime, if overtime is in progress.
+pub(crate) fn notify_overtime_single(
+    In(client_id): In<ClientId>,
+    msgs: OvertimeMsgParam,
+    overtime: Res<GameOvertime>,
+    mut sender: GameSender,
+)
+{
+    let Some(msg) = msgs.make_msg(&overtime, Some(client_id)) else { return };
+    sender.send_to_client(msg, client_id);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Tracks sudden-death overtime.
+///
+/// Overtime extends [`GameState::Play`] for up to [`GameDurationConfig::overtime_ticks`]. Only the players tied
+/// for first may score, and the first of them to click wins.
+#[derive(Resource, Default, Debug)]
+pub struct GameOvertime
+{
+    started: bool,
+    /// Players tied for first when overtime started.
+    players: BTreeSet<ClientId>,
+    winner: Option<ClientId>,
+}
+
+impl GameOvertime
+{
+    /// Returns `true` if overtime has started and nobody has won yet.
+    pub fn in_progress(&self) -> bool
+    {
+        self.started && self.winner.is_none()
+    }
+
+    /// Returns `true` if overtime has started.
+    pub fn is_started(&self) -> bool
+    {
+        self.started
+    }
+
+    /// Returns `true` if `client_id` may score in overtime.
+    pub fn can_score(&self, client_id: ClientId) -> bool
+    {
+        self.in_progress() && self.players.contains(&client_id)
+    }
+
+    /// The player who won in overtime.
+    pub fn winner(&self) -> Option<ClientId>
+    {
+        self.winner
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Overtime plugin.
+pub(crate) struct GameOvertimePlugin;
+
+impl Plugin for GameOvertimePlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        // overtime resumes in play after a pause, so the timer needs to be re-synced
+        app.init_resource::<GameOvertime>()
+            .add_observer(claim_overtime_win)
+            .add_systems(OnEnter(GameState::Play), notify_overtime_all);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
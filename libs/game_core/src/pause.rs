@@ -0,0 +1,279 @@
+use std::collections::{HashMap, HashSet};
+
+use bevy::prelude::*;
+use bevy_cobweb::prelude::*;
+use bevy_girk_game_fw::*;
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Counts paused ticks and resumes the game when a pause reaches its max length.
+fn advance_pause_tick(game_ctx: Res<ClickGameContext>, mut pause: ResMut<GamePause>)
+{
+    pause.paused_ticks += 1;
+    pause.current_pause_ticks += 1;
+
+    if !pause.paused || pause.current_pause_ticks < game_ctx.pause_config().max_pause_ticks() {
+        return;
+    }
+    pause.resume();
+    tracing::info!("pause time limit reached, resuming");
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Removes the pause and resume votes of players who forfeit.
+fn drop_forfeited_votes(trigger: Trigger<PlayerForfeited>, mut c: Commands, mut pause: ResMut<GamePause>)
+{
+    let client_id = trigger.event().client_id;
+    let removed_pause = pause.pause_votes.remove(&client_id);
+    let removed_resume = pause.resume_votes.remove(&client_id);
+    if removed_pause || removed_resume {
+        c.syscall((), notify_pause_votes);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Notifies all clients of the current pause and resume votes.
+fn notify_pause_votes(
+    game_ctx: Res<ClickGameContext>,
+    players: Query<(&PlayerId, &PlayerConnection), Without<PlayerBot>>,
+    pause: Res<GamePause>,
+    mut sender: GameSender,
+)
+{
+    let votes_needed = match game_ctx.pause_config().mode() {
+        PauseMode::Vote => majority(num_voters(&players)) as u32,
+        _ => 1,
+    };
+
+    sender.send_to_all(GameMsg::PauseVotes {
+        pause_votes: pause.pause_votes.len() as u32,
+        resume_votes: pause.resume_votes.len() as u32,
+        votes_needed,
+    });
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Number of votes needed for a majority of `num_voters`.
+fn majority(num_voters: usize) -> usize
+{
+    num_voters / 2 + 1
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Number of players who can vote on pauses. Disconnected players don't count.
+fn num_voters(players: &Query<(&PlayerId, &PlayerConnection), Without<PlayerBot>>) -> usize
+{
+    players.iter().filter(|(_, connection)| connection.is_connected()).count()
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Checks if a player is allowed to pause or resume the game.
+fn check_pause_permission(config: &PauseConfig, owner: Option<ClientId>, client_id: ClientId) -> bool
+{
+    match config.mode() {
+        PauseMode::Disabled => false,
+        PauseMode::OwnerOnly => owner == Some(client_id),
+        PauseMode::Vote => true,
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Handles a player's request to pause the game.
+///
+/// Bots and disconnected players don't count as voters.
+pub(crate) fn handle_pause_request(
+    In((player_entity, ())): In<(Entity, ())>,
+    world: &mut World,
+)
+{
+    let result = world.syscall(player_entity, try_vote_pause);
+    on_pause_vote(world, player_entity, ClientRequest::RequestPause, result);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Handles a player's request to resume a paused game.
+pub(crate) fn handle_resume_request(
+    In((player_entity, ())): In<(Entity, ())>,
+    world: &mut World,
+)
+{
+    let result = world.syscall(player_entity, try_vote_resume);
+    on_pause_vote(world, player_entity, ClientRequest::RequestResume, result);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn on_pause_vote(
+    world: &mut World,
+    player_entity: Entity,
+    request: ClientRequest,
+    result: Result<(), RejectionReason>,
+)
+{
+    let Some(player_id) = world.get::<PlayerId>(player_entity).copied() else {
+        tracing::error!("pause request: unknown player entity");
+        return;
+    };
+
+    match result {
+        Ok(()) => {
+            world.trigger(RequestAccepted { client_id: player_id.id, request });
+            world.syscall((), notify_pause_votes);
+        }
+        Err(reason) => world.syscall((player_id.id, request, reason), notify_request_rejected),
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn try_vote_pause(
+    In(player_entity): In<Entity>,
+    game_ctx: Res<ClickGameContext>,
+    players: Query<(&PlayerId, &PlayerConnection), Without<PlayerBot>>,
+    mut pause: ResMut<GamePause>,
+) -> Result<(), RejectionReason>
+{
+    let Ok((player_id, _)) = players.get(player_entity) else { return Err(RejectionReason::Invalid) };
+    let config = game_ctx.pause_config();
+    if !check_pause_permission(config, pause.owner, player_id.id) {
+        return Err(RejectionReason::Invalid);
+    }
+    if pause.paused {
+        return Ok(());
+    }
+    if pause.pauses_used(player_id.id) >= config.max_pauses_per_player() {
+        return Err(RejectionReason::PauseLimitReached);
+    }
+
+    // wait for a majority
+    pause.pause_votes.insert(player_id.id);
+    if config.mode() == PauseMode::Vote && pause.pause_votes.len() < majority(num_voters(&players)) {
+        return Ok(());
+    }
+
+    // pause
+    // - every player who voted for the pause uses up one of their pauses
+    let voters: Vec<ClientId> = pause.pause_votes.drain().collect();
+    for voter in voters {
+        *pause.pauses_used.entry(voter).or_default() += 1;
+    }
+    pause.paused = true;
+    pause.current_pause_ticks = 0;
+    pause.resume_votes.clear();
+    tracing::info!(player_id.id, "pausing game");
+
+    Ok(())
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn try_vote_resume(
+    In(player_entity): In<Entity>,
+    game_ctx: Res<ClickGameContext>,
+    players: Query<(&PlayerId, &PlayerConnection), Without<PlayerBot>>,
+    mut pause: ResMut<GamePause>,
+) -> Result<(), RejectionReason>
+{
+    let Ok((player_id, _)) = players.get(player_entity) else { return Err(RejectionReason::Invalid) };
+    let config = game_ctx.pause_config();
+    if !check_pause_permission(config, pause.owner, player_id.id) {
+        return Err(RejectionReason::Invalid);
+    }
+    if !pause.paused {
+        return Ok(());
+    }
+
+    // wait for a majority
+    pause.resume_votes.insert(player_id.id);
+    if config.mode() == PauseMode::Vote && pause.resume_votes.len() < majority(num_voters(&players)) {
+        return Ok(());
+    }
+
+    pause.resume();
+    tracing::info!(player_id.id, "resuming game");
+
+    Ok(())
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Tracks pause requests and how long the game has been paused.
+///
+/// The game state update moves [`GameState::Play`] to [`GameState::Paused`] while [`Self::is_paused`] is true.
+#[derive(Resource, Default, Debug)]
+pub struct GamePause
+{
+    /// The lobby owner, who controls pauses in [`PauseMode::OwnerOnly`].
+    owner: Option<ClientId>,
+    paused: bool,
+    /// Total ticks spent in [`GameState::Paused`].
+    paused_ticks: u32,
+    /// Ticks spent in the current pause.
+    current_pause_ticks: u32,
+    pause_votes: HashSet<ClientId>,
+    resume_votes: HashSet<ClientId>,
+    /// [ client id : number of pauses used ]
+    pauses_used: HashMap<ClientId, u8>,
+}
+
+impl GamePause
+{
+    pub fn new(owner: Option<ClientId>) -> Self
+    {
+        Self { owner, ..Default::default() }
+    }
+
+    /// Returns `true` if the game should be paused.
+    pub fn is_paused(&self) -> bool
+    {
+        self.paused
+    }
+
+    /// Total ticks spent in [`GameState::Paused`]. These don't count toward the game duration.
+    pub fn paused_ticks(&self) -> u32
+    {
+        self.paused_ticks
+    }
+
+    /// Number of pauses `client_id` has used.
+    pub fn pauses_used(&self, client_id: ClientId) -> u8
+    {
+        self.pauses_used.get(&client_id).copied().unwrap_or_default()
+    }
+
+    fn resume(&mut self)
+    {
+        self.paused = false;
+        self.pause_votes.clear();
+        self.resume_votes.clear();
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Pause plugin.
+pub(crate) struct GamePausePlugin;
+
+impl Plugin for GamePausePlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.add_observer(drop_forfeited_votes).add_systems(
+            Update,
+            advance_pause_tick
+                .in_set(GameSet::Paused)
+                .in_set(TickUpdateSet),
+        );
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,136 @@
+use std::fmt;
+
+use bevy::prelude::*;
+use bevy_girk_game_fw::*;
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Min number of characters in a player name.
+pub const MIN_PLAYER_NAME_CHARS: usize = 3;
+/// Max number of characters in a player name.
+pub const MAX_PLAYER_NAME_CHARS: usize = 16;
+
+/// Words that may not appear in player names.
+///
+/// Words are matched in lowercase against each word of a name, and against the whole name with separators
+/// removed, so e.g. `Ad_Min` is blocked but `Observer` is allowed.
+const BLOCKED_NAME_WORDS: &[&str] = &["admin", "moderator", "server", "fuck", "shit", "cunt", "bitch", "nazi"];
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Renames a player if their requested name is valid and not used by another player.
+///
+/// Players can only change their names before [`GameState::Play`]. Requests for the player's current name are
+/// ignored.
+pub(crate) fn handle_player_name_request(
+    In((player_entity, name)): In<(Entity, String)>,
+    mut c: Commands,
+    mut sender: GameSender,
+    game_state: Res<State<GameState>>,
+    mut players: Query<(Entity, &PlayerId, &mut PlayerName)>,
+)
+{
+    let Ok((_, player_id, player_name)) = players.get(player_entity) else {
+        tracing::error!("handle player name request: unknown player entity");
+        return;
+    };
+    let client_id = player_id.id;
+    if player_name.name == name {
+        return;
+    }
+
+    let mut reject = |reason: RejectionReason, name: String| {
+        let request = ClientRequest::SetPlayerName { name };
+        sender.send_to_client(GameMsg::RequestRejected { reason, request }, client_id);
+    };
+    if !matches!(**game_state, GameState::Init | GameState::Prep) {
+        tracing::debug!(client_id, ?name, "rejected player name, the game has started");
+        reject(RejectionReason::ModeMismatch, name);
+        return;
+    }
+    if let Err(err) = validate_player_name(&name) {
+        tracing::debug!(client_id, ?name, ?err, "rejected player name");
+        reject(RejectionReason::InvalidName, name);
+        return;
+    }
+    let taken = players
+        .iter()
+        .any(|(entity, _, other)| entity != player_entity && other.name.eq_ignore_ascii_case(&name));
+    if taken {
+        tracing::debug!(client_id, ?name, "rejected player name, the name is taken");
+        reject(RejectionReason::NameTaken, name);
+        return;
+    }
+
+    let Ok((_, player_id, mut player_name)) = players.get_mut(player_entity) else { return };
+    tracing::info!(player_id.id, ?name, "renamed player");
+    player_name.name = name.clone();
+    c.trigger(RequestAccepted { client_id: player_id.id, request: ClientRequest::SetPlayerName { name } });
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Gets the name of a player that hasn't chosen a name.
+pub fn default_player_name(client_id: ClientId) -> String
+{
+    format!("player{}", client_id)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Checks if a player name is allowed.
+///
+/// Names may contain ASCII letters, digits, `_`, `-`, and single spaces between words.
+pub fn validate_player_name(name: &str) -> Result<(), InvalidPlayerName>
+{
+    let num_chars = name.chars().count();
+    if num_chars < MIN_PLAYER_NAME_CHARS {
+        return Err(InvalidPlayerName::TooShort);
+    }
+    if num_chars > MAX_PLAYER_NAME_CHARS {
+        return Err(InvalidPlayerName::TooLong);
+    }
+
+    let valid_char = |c: char| c.is_ascii_alphanumeric() || c == '_' || c == '-' || c == ' ';
+    if !name.chars().all(valid_char) || name.trim() != name || name.contains("  ") {
+        return Err(InvalidPlayerName::BadCharacters);
+    }
+
+    let lowercase = name.to_ascii_lowercase();
+    let joined: String = lowercase.chars().filter(|c| c.is_ascii_alphanumeric()).collect();
+    let is_blocked = |word: &str| BLOCKED_NAME_WORDS.contains(&word);
+    if is_blocked(&joined) || lowercase.split(|c: char| !c.is_ascii_alphanumeric()).any(is_blocked) {
+        return Err(InvalidPlayerName::Blocked);
+    }
+
+    Ok(())
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Reasons a player name may be rejected by [`validate_player_name`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum InvalidPlayerName
+{
+    TooShort,
+    TooLong,
+    BadCharacters,
+    Blocked,
+}
+
+impl fmt::Display for InvalidPlayerName
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self {
+            Self::TooShort => write!(f, "must have at least {} characters", MIN_PLAYER_NAME_CHARS),
+            Self::TooLong => write!(f, "must have at most {} characters", MAX_PLAYER_NAME_CHARS),
+            Self::BadCharacters => write!(f, "may only use letters, digits, '_', '-', and single spaces"),
+            Self::Blocked => write!(f, "is not allowed"),
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
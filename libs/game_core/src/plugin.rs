@@ -23,10 +23,22 @@ impl Plugin for GamePlugin
     fn build(&self, app: &mut App)
     {
         app.add_plugins(GameReplicationPlugin)
+            .add_plugins(GameBotPlugin)
+            .add_plugins(GameChatPlugin)
+            .add_plugins(GameClickPlugin)
+            .add_plugins(GameConnectionPlugin)
             .add_plugins(GameSetsPlugin)
             .add_plugins(GameSetupPlugin)
+            .add_plugins(GameModePlugin)
+            .add_plugins(GameOvertimePlugin)
+            .add_plugins(GamePausePlugin)
+            .add_plugins(ReplayRecorderPlugin)
+            .add_plugins(GameStateHashPlugin)
             .add_plugins(GameStatePlugin)
+            .add_plugins(GameTargetPlugin)
+            .add_plugins(GameTeamPlugin)
             .add_plugins(GameTickPlugin)
+            .add_plugins(GameVisibilityPlugin)
             .configure_sets(
                 Update,
                 (GameStateUpdateSet, TickUpdateSet)
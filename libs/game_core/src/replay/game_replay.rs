@@ -0,0 +1,125 @@
+use std::collections::HashSet;
+use std::path::Path;
+
+use bevy_girk_game_fw::*;
+use bevy_girk_utils::*;
+use serde::{Deserialize, Serialize};
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A player in the initial roster of a recorded game.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReplayPlayer
+{
+    pub client_id: ClientId,
+    pub name: String,
+    pub team: Option<u8>,
+    /// Set if the player was a bot.
+    pub bot: Option<BotDifficulty>,
+    pub handicap: PlayerHandicap,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A client request that was accepted by the game's request handler.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReplayInput
+{
+    /// The value of [`GameTick`] when the request was applied.
+    pub tick: Tick,
+    pub client_id: ClientId,
+    pub request: ClientRequest,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A player's client connecting or disconnecting during a recorded game.
+#[derive(Debug, Copy, Clone, Serialize, Deserialize)]
+pub struct ReplayConnectionChange
+{
+    /// The value of [`GameTick`] when the change was applied.
+    pub tick: Tick,
+    pub client_id: ClientId,
+    pub connected: bool,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Everything needed to deterministically re-run a game.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClickGameReplay
+{
+    /// Context of the recorded game, including the seed for its deterministic random number generator.
+    pub game_context: ClickGameContext,
+    /// Initial players.
+    pub players: Vec<ReplayPlayer>,
+    /// The lobby owner, if they were a player.
+    pub owner: Option<ClientId>,
+    /// Initial watchers.
+    pub watchers: Vec<ClientId>,
+    /// Set if players could only see their own score until the game ended.
+    pub hidden_scores: bool,
+    /// Accepted client requests in the order they were applied.
+    pub inputs: Vec<ReplayInput>,
+    /// Player connection changes in the order they were applied.
+    pub connection_changes: Vec<ReplayConnectionChange>,
+    /// The report produced when the recorded game ended.
+    pub report: ClickGameOverReport,
+}
+
+impl ClickGameReplay
+{
+    /// Makes a game initializer with the recorded context, roster and settings.
+    pub fn initializer(&self) -> ClickGameInitializer
+    {
+        let players = self
+            .players
+            .iter()
+            .map(|player| {
+                let player_state = PlayerState {
+                    id: PlayerId { id: player.client_id },
+                    name: PlayerName { name: player.name.clone() },
+                    team: player.team.map(|team| PlayerTeam { team }),
+                    handicap: player.handicap,
+                    ..Default::default()
+                };
+                (player.client_id, player_state)
+            })
+            .collect();
+        let bots = self
+            .players
+            .iter()
+            .filter_map(|player| Some((player.client_id, player.bot?)))
+            .collect();
+        let watchers = self.watchers.iter().copied().collect();
+
+        ClickGameInitializer {
+            game_context: self.game_context.clone(),
+            players,
+            bots,
+            owner: self.owner,
+            watchers,
+            viewers: HashSet::default(),
+            hidden_scores: self.hidden_scores,
+        }
+    }
+
+    /// Writes the replay to a file.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), String>
+    {
+        std::fs::write(path.as_ref(), ser_msg(self))
+            .map_err(|err| format!("failed writing replay to {:?}: {err:?}", path.as_ref()))
+    }
+
+    /// Reads a replay from a file.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, String>
+    {
+        let bytes = std::fs::read(path.as_ref())
+            .map_err(|err| format!("failed reading replay from {:?}: {err:?}", path.as_ref()))?;
+        deser_msg::<Self>(&bytes).ok_or_else(|| format!("failed deserializing replay from {:?}", path.as_ref()))
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
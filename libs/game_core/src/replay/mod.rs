@@ -0,0 +1,9 @@
+mod game_replay;
+mod replay_playback;
+mod replay_recorder;
+mod replay_runner;
+
+pub use game_replay::*;
+pub use replay_playback::*;
+pub use replay_recorder::*;
+pub use replay_runner::*;
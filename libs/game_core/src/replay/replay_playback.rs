@@ -0,0 +1,212 @@
+use std::sync::{Arc, Mutex};
+
+use bevy::prelude::*;
+use bevy_girk_game_fw::*;
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Number of game framework ticks per game tick when playing a replay at [`ReplaySpeed::Normal`].
+///
+/// Replay game apps should run their framework this many times faster than the recorded game so playback can be
+/// sped up without changing the framework tick rate.
+pub const REPLAY_TICK_MULTIPLIER: u32 = 4;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Decides whether game logic should advance this tick.
+fn update_replay_step(
+    control: Res<ReplayControl>,
+    game_tick: Res<GameTick>,
+    mut counter: Local<u32>,
+    mut step: ResMut<ReplayStep>,
+)
+{
+    let mut inner = control.lock();
+    inner.current_tick = ***game_tick;
+    step.paused = false;
+
+    // fast-forward until the seek target is reached
+    if let Some(target) = inner.seek_target {
+        if inner.current_tick < target {
+            step.ready = true;
+            return;
+        }
+        inner.seek_target = None;
+    }
+
+    if inner.paused {
+        step.ready = false;
+        step.paused = true;
+        return;
+    }
+
+    *counter += 1;
+    let divisor = REPLAY_TICK_MULTIPLIER / inner.speed.multiplier();
+    step.ready = (*counter % divisor.max(1)) == 0;
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn replay_step_ready(step: Res<ReplayStep>) -> bool
+{
+    step.ready
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn replay_paused(step: Res<ReplayStep>) -> bool
+{
+    step.paused
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Resource, Default)]
+struct ReplayStep
+{
+    /// Set if game logic should advance this tick.
+    ready: bool,
+    /// Set if playback is paused. The game framework doesn't tick while paused.
+    paused: bool,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Replay playback speed.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub enum ReplaySpeed
+{
+    #[default]
+    Normal,
+    Double,
+}
+
+impl ReplaySpeed
+{
+    pub fn multiplier(&self) -> u32
+    {
+        match self {
+            Self::Normal => 1,
+            Self::Double => 2,
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Debug, Default)]
+struct ReplayControlInner
+{
+    paused: bool,
+    speed: ReplaySpeed,
+    seek_target: Option<u32>,
+    current_tick: u32,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Shared handle for controlling replay playback.
+///
+/// Clones of this handle can be held by a replay game app and the app that is viewing it.
+#[derive(Resource, Debug, Default, Clone)]
+pub struct ReplayControl
+{
+    inner: Arc<Mutex<ReplayControlInner>>,
+}
+
+impl ReplayControl
+{
+    fn lock(&self) -> std::sync::MutexGuard<ReplayControlInner>
+    {
+        self.inner.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    pub fn is_paused(&self) -> bool
+    {
+        self.lock().paused
+    }
+
+    pub fn set_paused(&self, paused: bool)
+    {
+        self.lock().paused = paused;
+    }
+
+    pub fn speed(&self) -> ReplaySpeed
+    {
+        self.lock().speed
+    }
+
+    pub fn set_speed(&self, speed: ReplaySpeed)
+    {
+        self.lock().speed = speed;
+    }
+
+    /// The game tick the replay has advanced to.
+    pub fn current_tick(&self) -> u32
+    {
+        self.lock().current_tick
+    }
+
+    /// Fast-forwards the replay to `tick`.
+    ///
+    /// Returns `false` if `tick` is in the past, in which case the replay must be restarted with
+    /// [`Self::restart_at`].
+    pub fn seek(&self, tick: u32) -> bool
+    {
+        let mut inner = self.lock();
+        if tick < inner.current_tick {
+            return false;
+        }
+        inner.seek_target = Some(tick);
+        true
+    }
+
+    /// Resets playback progress so a restarted replay will fast-forward to `tick`.
+    pub fn restart_at(&self, tick: u32)
+    {
+        let mut inner = self.lock();
+        inner.current_tick = 0;
+        inner.seek_target = Some(tick);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Plays back a replay in a game app, with pause, speed, and seek controls from a [`ReplayControl`].
+///
+/// Add this to a game app after [`GamePlugin`] and [`add_replay_inputs`]. The game framework should tick
+/// [`REPLAY_TICK_MULTIPLIER`] times faster than the recorded game.
+pub struct ReplayPlaybackPlugin
+{
+    pub control: ReplayControl,
+}
+
+impl Plugin for ReplayPlaybackPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.insert_resource(self.control.clone())
+            .init_resource::<ReplayStep>()
+            .add_systems(First, update_replay_step);
+
+        for set in [GameSet::PostInit, GameSet::Prep, GameSet::Play, GameSet::Paused, GameSet::GameOver] {
+            app.configure_sets(Update, set.run_if(replay_step_ready));
+        }
+
+        // hold the framework while paused so it doesn't e.g. end the game after the game-over timeout
+        let fw_sets = [
+            GameFwSet::Start,
+            GameFwSet::PreLogic,
+            GameFwSet::Logic,
+            GameFwSet::PostLogic,
+            GameFwSet::End,
+        ];
+        for set in fw_sets {
+            app.configure_sets(Update, set.run_if(not(replay_paused)));
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
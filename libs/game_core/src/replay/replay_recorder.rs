@@ -0,0 +1,155 @@
+use std::path::PathBuf;
+
+use bevy::prelude::*;
+use bevy_girk_game_fw::*;
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Writes the finished replay to [`ReplayOutput`] if it exists.
+fn write_replay_file(recorder: Res<ReplayRecorder>, output: Option<Res<ReplayOutput>>)
+{
+    let Some(output) = output else { return };
+    let Some(replay) = recorder.replay() else {
+        tracing::error!("failed writing replay file, the replay is not finished");
+        return;
+    };
+
+    match replay.save(&output.path) {
+        Ok(()) => tracing::info!(?output.path, "wrote replay file"),
+        Err(err) => tracing::error!("{err}"),
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Records requests in the [`ReplayRecorder`] once they are accepted.
+fn record_accepted_request(
+    trigger: Trigger<RequestAccepted>,
+    game_tick: Res<GameTick>,
+    mut recorder: ResMut<ReplayRecorder>,
+)
+{
+    let RequestAccepted { client_id, request } = trigger.event().clone();
+    recorder.record(**game_tick, client_id, request);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Records accepted client requests so the game can be re-run later.
+///
+/// Requests that don't affect the game (e.g. [`ClientRequest::GetGameState`]) are not recorded.
+///
+/// Initialized from the [`ClickGameInitializer`] on startup.
+#[derive(Resource, Debug)]
+pub struct ReplayRecorder
+{
+    game_context: ClickGameContext,
+    players: Vec<ReplayPlayer>,
+    owner: Option<ClientId>,
+    watchers: Vec<ClientId>,
+    hidden_scores: bool,
+    inputs: Vec<ReplayInput>,
+    connection_changes: Vec<ReplayConnectionChange>,
+    report: Option<ClickGameOverReport>,
+}
+
+impl ReplayRecorder
+{
+    pub(crate) fn new(initializer: &ClickGameInitializer) -> Self
+    {
+        let mut players: Vec<ReplayPlayer> = initializer
+            .players
+            .values()
+            .map(|player| ReplayPlayer {
+                client_id: player.id.id,
+                name: player.name.name.clone(),
+                team: player.team.map(|team| team.team),
+                bot: initializer.bots.get(&player.id.id).copied(),
+                handicap: player.handicap,
+            })
+            .collect();
+        players.sort_unstable_by_key(|player| player.client_id);
+        let mut watchers: Vec<ClientId> = initializer.watchers.iter().copied().collect();
+        watchers.sort_unstable();
+
+        Self {
+            game_context: initializer.game_context.clone(),
+            players,
+            owner: initializer.owner,
+            watchers,
+            hidden_scores: initializer.hidden_scores,
+            inputs: Vec::default(),
+            connection_changes: Vec::default(),
+            report: None,
+        }
+    }
+
+    pub(crate) fn record(&mut self, tick: Tick, client_id: ClientId, request: ClientRequest)
+    {
+        if self.report.is_some() {
+            return;
+        }
+        self.inputs.push(ReplayInput { tick, client_id, request });
+    }
+
+    pub(crate) fn record_connection(&mut self, tick: Tick, client_id: ClientId, connected: bool)
+    {
+        if self.report.is_some() {
+            return;
+        }
+        self.connection_changes
+            .push(ReplayConnectionChange { tick, client_id, connected });
+    }
+
+    pub(crate) fn finish(&mut self, report: ClickGameOverReport)
+    {
+        self.report = Some(report);
+    }
+
+    /// Gets the recorded replay.
+    ///
+    /// Returns `None` if the game hasn't ended yet.
+    pub fn replay(&self) -> Option<ClickGameReplay>
+    {
+        let report = self.report.clone()?;
+        Some(ClickGameReplay {
+            game_context: self.game_context.clone(),
+            players: self.players.clone(),
+            owner: self.owner,
+            watchers: self.watchers.clone(),
+            hidden_scores: self.hidden_scores,
+            inputs: self.inputs.clone(),
+            connection_changes: self.connection_changes.clone(),
+            report,
+        })
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Where to write the replay file when the game ends.
+///
+/// If this resource is missing then no file will be written.
+#[derive(Resource, Debug, Clone)]
+pub struct ReplayOutput
+{
+    pub path: PathBuf,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Replay recording plugin.
+pub(crate) struct ReplayRecorderPlugin;
+
+impl Plugin for ReplayRecorderPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.add_observer(record_accepted_request)
+            .add_systems(OnEnter(GameState::GameOver), write_replay_file.after(set_game_end_flag));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
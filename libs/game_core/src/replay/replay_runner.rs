@@ -0,0 +1,153 @@
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+use bevy_cobweb::prelude::*;
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Feeds recorded requests into the request handler on the tick they were originally applied.
+fn apply_replay_inputs(world: &mut World)
+{
+    let tick = **world.resource::<GameTick>();
+
+    loop {
+        let mut inputs = world.resource_mut::<ReplayInputs>();
+        if !inputs
+            .inputs
+            .front()
+            .is_some_and(|input| *input.tick <= *tick)
+        {
+            break;
+        }
+        let Some(input) = inputs.inputs.pop_front() else { break };
+
+        if *input.tick < *tick {
+            tracing::warn!(?input, ?tick, "replay input was applied late");
+        }
+        handle_client_request(world, input.client_id, input.request);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Applies recorded player connection changes on the tick they were originally applied.
+fn apply_replay_connection_changes(world: &mut World)
+{
+    let tick = **world.resource::<GameTick>();
+
+    loop {
+        let mut changes = world.resource_mut::<ReplayConnectionChanges>();
+        if !changes
+            .changes
+            .front()
+            .is_some_and(|change| *change.tick <= *tick)
+        {
+            break;
+        }
+        let Some(change) = changes.changes.pop_front() else { break };
+
+        if *change.tick < *tick {
+            tracing::warn!(?change, ?tick, "replay connection change was applied late");
+        }
+        world.syscall((change.client_id, change.connected), apply_connection_change);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Resource)]
+struct ReplayInputs
+{
+    inputs: VecDeque<ReplayInput>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Recorded connection changes. Live connection tracking is disabled while this resource exists.
+#[derive(Resource)]
+pub(crate) struct ReplayConnectionChanges
+{
+    changes: VecDeque<ReplayConnectionChange>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Adds recorded requests to a game app. They will be fed into the game's request handler on the ticks they were
+/// originally applied.
+pub fn add_replay_inputs(app: &mut App, inputs: impl IntoIterator<Item = ReplayInput>)
+{
+    app.insert_resource(ReplayInputs { inputs: inputs.into_iter().collect() })
+        .add_systems(
+            Update,
+            apply_replay_inputs
+                .in_set(GameSet::PostInit)
+                .before(GameLogicSet::Admin),
+        );
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Adds recorded player connection changes to a game app. They will replace the game's live connection tracking,
+/// and be applied on the ticks they were originally applied.
+pub fn add_replay_connection_changes(app: &mut App, changes: impl IntoIterator<Item = ReplayConnectionChange>)
+{
+    app.insert_resource(ReplayConnectionChanges { changes: changes.into_iter().collect() })
+        .add_systems(
+            Update,
+            apply_replay_connection_changes
+                .in_set(GameSet::PostInit)
+                .before(forfeit_disconnected_players)
+                .before(GameLogicSet::Admin),
+        );
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Re-runs a recorded game headlessly and returns the game over report it produces.
+///
+/// The `app` must already contain the game framework (the same framework setup a game factory uses, minus any
+/// client connections). This function adds [`GamePlugin`] and the replay's initializer to the app, then calls
+/// `app.update()` until the game ends. Recorded requests are injected directly into the game's request handler.
+///
+/// Returns an error if the game doesn't end within the recorded game's duration (plus `max_extra_ticks`).
+pub fn rerun_replay(
+    app: &mut App,
+    replay: &ClickGameReplay,
+    max_extra_ticks: u32,
+) -> Result<ClickGameOverReport, String>
+{
+    app.add_plugins(GamePlugin)
+        .insert_resource(replay.initializer());
+    add_replay_inputs(app, replay.inputs.iter().cloned());
+    add_replay_connection_changes(app, replay.connection_changes.iter().copied());
+
+    // the recorded game may have run longer than the configured duration if it was paused
+    let total_ticks = replay.game_context.duration_config().total_ticks();
+    let max_updates = total_ticks.max(*replay.report.final_game_tick) + max_extra_ticks;
+    for _ in 0..max_updates {
+        app.update();
+
+        if let Some(replay) = app.world().resource::<ReplayRecorder>().replay() {
+            return Ok(replay.report);
+        }
+    }
+
+    Err(format!("replay did not finish within {max_updates} updates"))
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Re-runs a recorded game with [`rerun_replay`] and checks that it reproduces the recorded game over report.
+pub fn verify_replay(app: &mut App, replay: &ClickGameReplay, max_extra_ticks: u32) -> Result<(), String>
+{
+    let report = rerun_replay(app, replay, max_extra_ticks)?;
+    if report != replay.report {
+        return Err(format!("replay mismatch: expected {:?}, got {:?}", replay.report, report));
+    }
+
+    Ok(())
+}
+
+//-------------------------------------------------------------------------------------------------------------------
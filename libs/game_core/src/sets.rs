@@ -15,6 +15,7 @@ pub enum GameSet
     PostInit,
     Prep,
     Play,
+    Paused,
     GameOver,
     End,
 }
@@ -53,6 +54,12 @@ impl Plugin for GameSetsPlugin
                     .run_if(in_state(GameFwState::Game))
                     .run_if(in_state(GameState::Play)),
             )
+            .configure_sets(
+                Update,
+                GameSet::Paused
+                    .run_if(in_state(GameFwState::Game))
+                    .run_if(in_state(GameState::Paused)),
+            )
             // - This will only run in the span between entering 'game over' and the GameFwState moving to 'End',
             //   which is controlled by `GameFwConfig::max_end_ticks()`.
             //todo: allow GameOver to last indefinitely?
@@ -1,7 +1,9 @@
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 
 use bevy::prelude::*;
 use bevy_girk_game_fw::*;
+use bevy_replicon::prelude::*;
+use bevy_replicon_attributes::*;
 
 use crate::*;
 
@@ -16,28 +18,67 @@ fn setup_game(world: &mut World)
         .expect("ClickGameInitializer missing on startup");
 
     // resources
-    world.insert_resource::<GameRand>(GameRand::new(initializer.game_context.seed()));
+    world.insert_resource(ReplayRecorder::new(&initializer));
+    let mut game_rand = GameRand::new(initializer.game_context.seed());
+    let bot_seed = game_rand.next();
+    world.insert_resource::<GameRand>(game_rand);
+    world.insert_resource(GamePause::new(initializer.owner));
     world.insert_resource(initializer.game_context);
+    if initializer.hidden_scores {
+        world.insert_resource(HiddenScores);
+    }
 
     // players
     // - player map
     // - player entities
+    // - score entities
     let mut client_entity_map = HashMap::<ClientId, Entity>::default();
+    let mut teams = BTreeSet::<u8>::default();
+
+    // spawn players in client id order so entity order (and query iteration order) is the same in replays
+    let mut players: Vec<PlayerState> = initializer.players.into_values().collect();
+    players.sort_unstable_by_key(|player| player.id.id);
 
-    for (_, player_state) in initializer.players {
+    for mut player_state in players {
         // [ client id : entity ]
         let mut entity_commands = world.spawn_empty();
         client_entity_map.insert(player_state.id.id, entity_commands.id());
 
         // add player entity
-        entity_commands.insert(player_state);
+        let player_state_id = player_state.id;
+        let team = player_state.team;
+        let score = player_state.score;
+        player_state.visibility = vis![Global];
+        entity_commands.insert((player_state, ClickTracker::default(), ClickTimeline::default()));
+        if let Some(team) = team {
+            entity_commands.insert(team);
+            teams.insert(team.team);
+        }
+        if let Some(difficulty) = initializer.bots.get(&player_state_id.id) {
+            entity_commands.insert(PlayerBot::new(*difficulty, bot_seed, player_state_id));
+        }
+
+        // add score entity
+        let player = entity_commands.id();
+        world.spawn((
+            ReplicatedScore { player, score },
+            Replicated,
+            score_visibility(player_state_id.id, initializer.hidden_scores),
+        ));
+    }
+
+    // teams
+    for team in teams {
+        let mut team_state = TeamState::new(team);
+        team_state.visibility = team_visibility(initializer.hidden_scores);
+        world.spawn(team_state);
     }
 
     world.insert_resource(PlayerMap::new(client_entity_map));
 
     // watchers
     // - watcher map
-    world.insert_resource(WatcherMap::new(initializer.watchers));
+    world.insert_resource(WatcherMap::new(initializer.watchers, initializer.viewers));
 }
 
 //-------------------------------------------------------------------------------------------------------------------
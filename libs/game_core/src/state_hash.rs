@@ -0,0 +1,239 @@
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy_girk_game_fw::*;
+use bevy_replicon::prelude::*;
+use bevy_replicon_attributes::*;
+use serde::{Deserialize, Serialize};
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Min number of seconds between logged desync reports from a client.
+const DESYNC_REPORT_COOLDOWN_SECS: u32 = 10;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn spawn_state_tick(mut c: Commands)
+{
+    c.spawn((StateTick::default(), Replicated, vis![Global]));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Copies the [`GameTick`] into the replicated [`StateTick`].
+fn update_state_tick(game_tick: Res<GameTick>, mut state_tick: Query<&mut StateTick>)
+{
+    let Ok(mut state_tick) = state_tick.single_mut() else { return };
+    state_tick.tick = ***game_tick;
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Sends state hashes to clients once per second so they can detect desyncs.
+///
+/// If scores are hidden, players only see their own score, so they are sent a hash without other players' scores
+/// or team scores while watchers are sent a hash of everything.
+fn send_state_hashes(
+    game_ctx: Res<ClickGameContext>,
+    game_tick: Res<GameTick>,
+    hidden: Option<Res<HiddenScores>>,
+    watchers: Res<WatcherMap>,
+    state: ReplicatedPlayerState,
+    clients: Query<&PlayerId, Without<PlayerBot>>,
+    mut sender: GameSender,
+)
+{
+    let tick = ***game_tick;
+    if tick % game_ctx.ticks_per_sec().max(1) != 0 {
+        return;
+    }
+
+    let hash = state.hash(|_| true);
+    if hidden.is_none() {
+        sender.send_to_all(GameMsg::StateHash { tick, hash });
+        return;
+    }
+
+    for watcher_id in watchers.iter() {
+        sender.send_to_client(GameMsg::StateHash { tick, hash }, watcher_id);
+    }
+    // bots don't have clients
+    for player_id in clients.iter().map(|player_id| player_id.id) {
+        let hash = state.hash(|id| id == Some(player_id));
+        sender.send_to_client(GameMsg::StateHash { tick, hash }, player_id);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Logs desyncs reported by clients.
+///
+/// Each client's reports are logged at most once every [`DESYNC_REPORT_COOLDOWN_SECS`].
+pub(crate) fn handle_desync_report(
+    In((client_id, tick, expected, found)): In<(ClientId, u32, u64, u64)>,
+    game_ctx: Res<ClickGameContext>,
+    game_tick: Res<GameTick>,
+    mut limiter: ResMut<DesyncReportLimiter>,
+)
+{
+    let current_tick = ***game_tick;
+    let cooldown_ticks = DESYNC_REPORT_COOLDOWN_SECS * game_ctx.ticks_per_sec();
+    if let Some(last_tick) = limiter.last_report.get(&client_id) {
+        if current_tick.saturating_sub(*last_tick) < cooldown_ticks {
+            tracing::trace!(client_id, tick, "ignoring rate-limited desync report");
+            return;
+        }
+    }
+    limiter.last_report.insert(client_id, current_tick);
+
+    tracing::warn!(client_id, tick, expected, found, "client reported a desync");
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Server-side record of desync reports from each client, used for rate limiting.
+#[derive(Resource, Default, Debug)]
+pub(crate) struct DesyncReportLimiter
+{
+    /// [ client id : game tick of the last logged report ]
+    last_report: HashMap<ClientId, u32>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Replicated copy of the [`GameTick`].
+///
+/// Clients use this to tell which game tick their replicated state is from, so they only compare
+/// [`GameMsg::StateHash`] against state from the same tick.
+#[derive(Component, Serialize, Deserialize, Default, Copy, Clone, Eq, PartialEq, Debug)]
+pub struct StateTick
+{
+    pub tick: u32,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Deterministic FNV-1a hasher for state hashes.
+///
+/// Unlike the std default hasher, the output is stable across builds and platforms, so hashes computed by the
+/// game and by clients can be compared.
+pub struct StateHasher(u64);
+
+impl Default for StateHasher
+{
+    fn default() -> Self
+    {
+        Self(0xcbf29ce484222325)
+    }
+}
+
+impl Hasher for StateHasher
+{
+    fn finish(&self) -> u64
+    {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8])
+    {
+        for byte in bytes {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(0x100000001b3);
+        }
+    }
+
+    // pointer-sized integers are widened so 32-bit (wasm) clients match 64-bit servers
+    fn write_usize(&mut self, i: usize)
+    {
+        self.write_u64(i as u64);
+    }
+
+    fn write_isize(&mut self, i: isize)
+    {
+        self.write_i64(i as i64);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Accesses all replicated player and team state.
+///
+/// Used by both the game and clients to compute state hashes.
+#[derive(SystemParam)]
+pub struct ReplicatedPlayerState<'w, 's>
+{
+    players: Query<
+        'w,
+        's,
+        (
+            &'static PlayerId,
+            &'static PlayerName,
+            Option<&'static PlayerScore>,
+            &'static PlayerConnection,
+            &'static PlayerHandicap,
+            Option<&'static PlayerTeam>,
+            Option<&'static PlayerEliminated>,
+        ),
+    >,
+    teams: Query<'w, 's, &'static TeamScore>,
+}
+
+impl ReplicatedPlayerState<'_, '_>
+{
+    /// Hashes the players and teams in a deterministic order, leaving out scores that don't pass `filter`.
+    ///
+    /// The filter is passed `Some(client id)` for player scores and `None` for teams. Players without a score
+    /// (e.g. on clients that can't see it) are hashed the same as players whose score was filtered out.
+    pub fn hash(&self, filter: impl Fn(Option<ClientId>) -> bool) -> u64
+    {
+        let mut players: Vec<_> = self
+            .players
+            .iter()
+            .map(|(player_id, name, score, connection, handicap, team, eliminated)| {
+                let score = score.filter(|_| filter(Some(player_id.id)));
+                (player_id, name, score, connection, handicap, team, eliminated)
+            })
+            .collect();
+        players.sort_unstable_by_key(|(player_id, ..)| player_id.id);
+
+        let mut teams: Vec<_> = self.teams.iter().filter(|_| filter(None)).collect();
+        teams.sort_unstable_by_key(|team| team.team);
+
+        let mut hasher = StateHasher::default();
+        players.hash(&mut hasher);
+        teams.hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Desync detection plugin.
+///
+/// Clients compare the hashes sent by the game against their own replicated state from the same tick.
+pub(crate) struct GameStateHashPlugin;
+
+impl Plugin for GameStateHashPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.init_resource::<DesyncReportLimiter>()
+            .add_systems(Startup, spawn_state_tick)
+            .add_systems(
+                Update,
+                (
+                    update_state_tick,
+                    send_state_hashes.run_if(not(in_state(GameState::GameOver))),
+                )
+                    .chain()
+                    .in_set(GameSet::PostInit)
+                    .after(GameLogicSet::Update),
+            );
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -1,3 +1,7 @@
+use std::collections::BTreeMap;
+
+use bevy::ecs::query::QueryData;
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 use bevy_girk_game_fw::*;
 use bevy_girk_utils::apply_state_transitions;
@@ -7,17 +11,40 @@ use crate::*;
 
 //-------------------------------------------------------------------------------------------------------------------
 
-/// Check the game duration conditions and update the game state.
+/// Check the game duration conditions and the active game mode, then update the game state.
 fn update_game_state(
     game_ctx: Res<ClickGameContext>,
     game_tick: Res<GameTick>,
+    mode_finished: Res<GameModeFinished>,
+    pause: Res<GamePause>,
+    overtime: Res<GameOvertime>,
     current_game_state: Res<State<GameState>>,
     mut next_game_state: ResMut<NextState<GameState>>,
 )
 {
     // get expected state based on elapsed ticks
     let duration_config = game_ctx.duration_config();
-    let new_game_state = duration_config.expected_state(**game_tick);
+    let mut new_game_state = duration_config.expected_state(**game_tick, pause.paused_ticks());
+
+    // the game mode may end play early
+    if new_game_state == GameState::Play && **mode_finished {
+        new_game_state = GameState::GameOver;
+    }
+
+    // tied players play on in sudden-death overtime until someone wins or overtime runs out
+    if new_game_state == GameState::GameOver
+        && overtime.in_progress()
+        && duration_config
+            .remaining_overtime_ticks(**game_tick, pause.paused_ticks())
+            .is_some()
+    {
+        new_game_state = GameState::Play;
+    }
+
+    // players may pause play
+    if new_game_state == GameState::Play && pause.is_paused() {
+        new_game_state = GameState::Paused;
+    }
 
     // update the game state
     if new_game_state == **current_game_state {
@@ -29,24 +56,119 @@ fn update_game_state(
 
 //-------------------------------------------------------------------------------------------------------------------
 
-fn set_game_end_flag(
-    game_tick: Res<GameTick>,
-    players: Query<(&PlayerId, &PlayerScore)>,
+/// Player data collected for the game over report.
+#[derive(QueryData)]
+struct PlayerReportData
+{
+    id: &'static PlayerId,
+    name: &'static PlayerName,
+    score: &'static PlayerScore,
+    team: Option<&'static PlayerTeam>,
+    connection: &'static PlayerConnection,
+    tracker: &'static ClickTracker,
+    timeline: &'static ClickTimeline,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Accesses everything needed to build the game over report.
+#[derive(SystemParam)]
+pub(crate) struct GameOverReportParam<'w, 's>
+{
+    game_ctx: Res<'w, ClickGameContext>,
+    game_tick: Res<'w, GameTick>,
+    play_tick: Res<'w, PlayTick>,
+    watchers: Res<'w, WatcherMap>,
+    overtime: Res<'w, GameOvertime>,
+    players: Query<'w, 's, PlayerReportData>,
+}
+
+impl GameOverReportParam<'_, '_>
+{
+    fn report(&self) -> ClickGameOverReport
+    {
+        // play duration
+        let ticks_per_sec = self.game_ctx.ticks_per_sec().max(1);
+        let play_ticks = ***self.play_tick;
+        let num_buckets = play_ticks.div_ceil(ticks_per_sec) as usize;
+        let play_secs = play_ticks as f32 / ticks_per_sec as f32;
+
+        // collect player reports
+        let scores: Vec<PlayerScore> = self.players.iter().map(|player| *player.score).collect();
+        let ranks = competition_ranks(&scores);
+        let mut player_reports: Vec<ClickPlayerReport> = self
+            .players
+            .iter()
+            .zip(ranks)
+            .map(|(player, rank)| {
+                let score = *player.score;
+                let click_timeline = player.timeline.padded(num_buckets);
+                let peak_clicks_per_sec = click_timeline.iter().copied().max().unwrap_or_default();
+                let average_clicks_per_sec = if play_secs > 0.0 {
+                    score.score() as f32 / play_secs
+                } else {
+                    0.0
+                };
+
+                ClickPlayerReport {
+                    client_id: player.id.id,
+                    name: player.name.name.clone(),
+                    score,
+                    team: player.team.map(|team| team.team),
+                    connection: *player.connection,
+                    rank,
+                    click_timeline,
+                    peak_clicks_per_sec,
+                    average_clicks_per_sec,
+                    flagged: player.tracker.is_flagged(),
+                }
+            })
+            .collect();
+        player_reports.sort_unstable_by_key(|report| report.client_id);
+
+        // collect team reports
+        // - totals are computed from players directly in case team scores haven't been refreshed yet
+        let mut team_totals = BTreeMap::<u8, u32>::default();
+        for report in player_reports.iter() {
+            let Some(team) = report.team else { continue };
+            let total = team_totals.entry(team).or_default();
+            *total = total.saturating_add(report.score.score());
+        }
+        let team_scores: Vec<u32> = team_totals.values().copied().collect();
+        let team_reports = team_totals
+            .iter()
+            .zip(competition_ranks(&team_scores))
+            .map(|((&team, &score), rank)| ClickTeamReport { team, score, rank })
+            .collect();
+
+        ClickGameOverReport {
+            final_game_tick: **self.game_tick,
+            play_ticks: **self.play_tick,
+            watcher_count: self.watchers.len() as u32,
+            overtime_winner: self.overtime.winner(),
+            player_reports,
+            team_reports,
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) fn set_game_end_flag(
+    report: GameOverReportParam,
     mut game_end_flag: ResMut<GameEndFlag>,
+    mut recorder: ResMut<ReplayRecorder>,
 )
 {
-    // collect player reports
-    let player_reports = players
-        .iter()
-        .map(|(&player_id, &score)| ClickPlayerReport { client_id: player_id.id, score })
-        .collect();
-
     // build game over report
-    let game_over_report = ClickGameOverReport { final_game_tick: **game_tick, player_reports };
+    let game_over_report = report.report();
 
     // serialize it
     let game_over_report_final = GameOverReport::new(&game_over_report);
 
+    // finish the replay
+    recorder.finish(game_over_report.clone());
+
     // set the game end flag
     game_end_flag.set(game_over_report_final);
     tracing::info!("game end flag set");
@@ -71,6 +193,70 @@ pub(crate) fn notify_game_state_all(game_state: Res<State<GameState>>, mut sende
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Makes a state timer message for the current game state.
+fn make_state_timer_msg(
+    game_ctx: &ClickGameContext,
+    game_tick: Tick,
+    game_state: GameState,
+    mode_finished: bool,
+    paused_ticks: u32,
+) -> Option<GameMsg>
+{
+    // the game state may end early, in which case the duration config doesn't match
+    let duration_config = game_ctx.duration_config();
+    if mode_finished || duration_config.expected_state(game_tick, paused_ticks) != game_state {
+        return None;
+    }
+    let remaining_ticks = duration_config.remaining_ticks(game_tick, paused_ticks)?;
+
+    let ticks_per_sec = game_ctx.ticks_per_sec();
+
+    Some(GameMsg::StateTimer { game_state, remaining_ticks, ticks_per_sec })
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Notify all clients of the time remaining in the current game state.
+pub(crate) fn notify_state_timer_all(
+    game_ctx: Res<ClickGameContext>,
+    game_tick: Res<GameTick>,
+    game_state: Res<State<GameState>>,
+    mode_finished: Res<GameModeFinished>,
+    pause: Res<GamePause>,
+    mut sender: GameSender,
+)
+{
+    let paused_ticks = pause.paused_ticks();
+    let Some(msg) = make_state_timer_msg(&game_ctx, **game_tick, **game_state, **mode_finished, paused_ticks)
+    else {
+        return;
+    };
+    sender.send_to_all(msg);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Notify a single client of the time remaining in the current game state.
+pub(crate) fn notify_state_timer_single(
+    In(client_id): In<ClientId>,
+    game_ctx: Res<ClickGameContext>,
+    game_tick: Res<GameTick>,
+    game_state: Res<State<GameState>>,
+    mode_finished: Res<GameModeFinished>,
+    pause: Res<GamePause>,
+    mut sender: GameSender,
+)
+{
+    let paused_ticks = pause.paused_ticks();
+    let Some(msg) = make_state_timer_msg(&game_ctx, **game_tick, **game_state, **mode_finished, paused_ticks)
+    else {
+        return;
+    };
+    sender.send_to_client(msg, client_id);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 /// Helper function-system for accessing the game state.
 pub(crate) fn get_game_state(game_state: Res<State<GameState>>) -> GameState
 {
@@ -100,6 +286,8 @@ pub enum GameState
     Init,
     Prep,
     Play,
+    /// Play is paused by players. Paused time doesn't count toward the game duration.
+    Paused,
     GameOver,
 }
 
@@ -123,6 +311,8 @@ impl Plugin for GameStatePlugin
             .add_systems(
                 Update,
                 (
+                    // check if play should go to overtime
+                    start_overtime.run_if(in_state(GameState::Play)),
                     // determine which game state the previous tick was in and set it
                     update_game_state,
                     apply_state_transitions,
@@ -132,8 +322,15 @@ impl Plugin for GameStatePlugin
                     .in_set(GameStateUpdateSet),
             )
             .add_systems(OnEnter(GameState::Init), notify_game_state_all)
-            .add_systems(OnEnter(GameState::Prep), notify_game_state_all)
-            .add_systems(OnEnter(GameState::Play), notify_game_state_all)
+            .add_systems(
+                OnEnter(GameState::Prep),
+                (notify_game_state_all, notify_state_timer_all),
+            )
+            .add_systems(
+                OnEnter(GameState::Play),
+                (notify_game_state_all, notify_state_timer_all),
+            )
+            .add_systems(OnEnter(GameState::Paused), notify_game_state_all)
             .add_systems(OnEnter(GameState::GameOver), (notify_game_state_all, set_game_end_flag));
     }
 }
@@ -0,0 +1,149 @@
+use bevy::prelude::*;
+use bevy_replicon::prelude::*;
+use bevy_replicon_attributes::*;
+use serde::{Deserialize, Serialize};
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Gets a random position along one axis, in `[0.0, 1.0)`.
+fn random_position(rand: &mut GameRand) -> f32
+{
+    (rand.next() % 1000) as f32 / 1000.0
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Despawns targets that have expired.
+fn expire_targets(mut c: Commands, play_tick: Res<PlayTick>, targets: Query<(Entity, &TargetExpiry)>)
+{
+    for (entity, expiry) in targets.iter() {
+        if expiry.0 > ***play_tick {
+            continue;
+        }
+        c.entity(entity).despawn();
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Spawns a target at a random position on each spawn interval in [`GameMode::Targets`].
+pub(crate) fn spawn_targets(
+    mut c: Commands,
+    game_ctx: Res<ClickGameContext>,
+    play_tick: Res<PlayTick>,
+    mut rand: ResMut<GameRand>,
+    mut spawner: ResMut<TargetSpawner>,
+)
+{
+    let GameMode::Targets { spawn_interval_ticks, lifetime_ticks } = game_ctx.game_mode() else { return };
+    let play_tick = ***play_tick;
+    if spawn_interval_ticks == 0 || (play_tick % spawn_interval_ticks) != 0 {
+        return;
+    }
+
+    let target = Target { id: spawner.next_id, x: random_position(&mut rand), y: random_position(&mut rand) };
+    spawner.next_id += 1;
+    c.spawn(TargetState::new(target, play_tick + lifetime_ticks));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn clear_targets(mut c: Commands, targets: Query<Entity, With<Target>>)
+{
+    for entity in targets.iter() {
+        c.entity(entity).despawn();
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Helper function-system for finding the oldest live target.
+pub(crate) fn get_oldest_target(targets: Query<&Target>) -> Option<u32>
+{
+    targets.iter().map(|target| target.id).min()
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A clickable target in [`GameMode::Targets`].
+#[derive(Component, Serialize, Deserialize, Copy, Clone, PartialEq, Debug)]
+pub struct Target
+{
+    /// Unique id of the target, used by [`PlayerInput::ClickTarget`].
+    pub id: u32,
+    /// Horizontal position of the target, in `[0.0, 1.0)`.
+    pub x: f32,
+    /// Vertical position of the target, in `[0.0, 1.0)`.
+    pub y: f32,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// The [`PlayTick`] when a target expires.
+///
+/// This component is not replicated.
+#[derive(Component, Debug)]
+pub struct TargetExpiry(pub u32);
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Targets are entities with the components bundled here.
+#[derive(Bundle)]
+pub struct TargetState
+{
+    pub target: Target,
+    pub expiry: TargetExpiry,
+    /// Targets are replicated
+    pub replicate: Replicated,
+    /// Targets have a visibility condition.
+    pub visibility: VisibilityCondition,
+}
+
+impl TargetState
+{
+    pub fn new(target: Target, expires_at: u32) -> Self
+    {
+        Self {
+            target,
+            expiry: TargetExpiry(expires_at),
+            replicate: Default::default(),
+            visibility: vis![Global],
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Assigns ids to new targets.
+#[derive(Resource, Default, Debug)]
+pub(crate) struct TargetSpawner
+{
+    next_id: u32,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Target plugin.
+///
+/// Targets are only spawned in [`GameMode::Targets`].
+pub(crate) struct GameTargetPlugin;
+
+impl Plugin for GameTargetPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.init_resource::<TargetSpawner>()
+            .add_systems(
+                Update,
+                (expire_targets, spawn_targets)
+                    .chain()
+                    .in_set(GameSet::Play)
+                    .in_set(GameLogicSet::Update),
+            )
+            .add_systems(OnEnter(GameState::GameOver), clear_targets);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
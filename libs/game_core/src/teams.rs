@@ -0,0 +1,92 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use bevy_replicon::prelude::*;
+use bevy_replicon_attributes::*;
+use serde::{Deserialize, Serialize};
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Recomputes team scores when player scores change.
+fn update_team_scores(
+    changed: Query<(), (Changed<PlayerScore>, With<PlayerTeam>)>,
+    players: Query<(&PlayerTeam, &PlayerScore)>,
+    mut teams: Query<&mut TeamScore>,
+)
+{
+    if changed.is_empty() {
+        return;
+    }
+
+    let mut totals = HashMap::<u8, u32>::default();
+    for (team, score) in players.iter() {
+        let total = totals.entry(team.team).or_default();
+        *total = total.saturating_add(score.score());
+    }
+
+    for mut team_score in teams.iter_mut() {
+        let total = totals.get(&team_score.team).copied().unwrap_or_default();
+        if team_score.score != total {
+            team_score.score = total;
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Aggregated score of a team.
+#[derive(Component, Default, Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct TeamScore
+{
+    /// Team index, starting at 0.
+    pub team: u8,
+    /// Sum of the scores of all players on the team.
+    pub score: u32,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Teams are entities with the components bundled here.
+#[derive(Bundle)]
+pub struct TeamState
+{
+    pub score: TeamScore,
+    /// Teams are replicated
+    pub replicate: Replicated,
+    /// Teams have a visibility condition.
+    pub visibility: VisibilityCondition,
+}
+
+impl TeamState
+{
+    pub fn new(team: u8) -> Self
+    {
+        Self {
+            score: TeamScore { team, score: 0 },
+            replicate: Default::default(),
+            visibility: vis![Global],
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Team plugin.
+pub(crate) struct GameTeamPlugin;
+
+impl Plugin for GameTeamPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.add_systems(
+            Update,
+            update_team_scores
+                .in_set(GameSet::Play)
+                .in_set(GameLogicSet::Update),
+        );
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
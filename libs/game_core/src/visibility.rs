@@ -0,0 +1,121 @@
+use bevy::prelude::*;
+use bevy_replicon::prelude::*;
+use bevy_replicon_attributes::*;
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Adds visibility attributes to clients when they connect.
+fn add_client_attributes(
+    mut attributes: ClientAttributes,
+    watchers: Res<WatcherMap>,
+    clients: Query<(Entity, &NetworkId), Added<ConnectedClient>>,
+)
+{
+    for (client_entity, network_id) in clients.iter() {
+        let client_id = network_id.get();
+        if watchers.is_watcher(client_id) {
+            attributes.add(client_entity, WatcherClient);
+        } else {
+            attributes.add(client_entity, PlayerClient(client_id));
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Copies player scores into their replicated score entities.
+fn update_replicated_scores(
+    players: Query<&PlayerScore, Changed<PlayerScore>>,
+    mut scores: Query<&mut ReplicatedScore>,
+)
+{
+    for mut replicated in scores.iter_mut() {
+        let Ok(score) = players.get(replicated.player) else { continue };
+        replicated.score = *score;
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Makes score and team entities visible to all clients.
+fn reveal_scores(
+    mut c: Commands,
+    mut entities: Query<&mut VisibilityCondition, Or<(With<ReplicatedScore>, With<TeamScore>)>>,
+)
+{
+    c.remove_resource::<HiddenScores>();
+    for mut visibility in entities.iter_mut() {
+        *visibility = vis![Global];
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Resource that exists while scores are hidden from players.
+#[derive(Resource)]
+pub struct HiddenScores;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Visibility attribute for watcher clients.
+#[derive(VisibilityAttribute, Default, PartialEq)]
+pub struct WatcherClient;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Visibility attribute for player clients.
+#[derive(VisibilityAttribute, Default, PartialEq)]
+pub struct PlayerClient(pub ClientId);
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Gets the visibility condition of a player's score entity.
+///
+/// If scores are hidden, players can only see their own score until the game ends. The rest of each player's state
+/// is always visible.
+pub(crate) fn score_visibility(client_id: ClientId, hidden_scores: bool) -> VisibilityCondition
+{
+    match hidden_scores {
+        true => vis![or(WatcherClient, PlayerClient(client_id))],
+        false => vis![Global],
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Gets the visibility condition of a team entity.
+///
+/// If scores are hidden, only watchers can see team scores until the game ends.
+pub(crate) fn team_visibility(hidden_scores: bool) -> VisibilityCondition
+{
+    match hidden_scores {
+        true => vis![WatcherClient],
+        false => vis![Global],
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Client visibility plugin.
+///
+/// Score and team visibility is set when they are spawned. Everything is revealed when the game ends.
+pub(crate) struct GameVisibilityPlugin;
+
+impl Plugin for GameVisibilityPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.add_systems(PreUpdate, add_client_attributes)
+            .add_systems(
+                Update,
+                update_replicated_scores
+                    .in_set(GameSet::PostInit)
+                    .after(GameLogicSet::Update),
+            )
+            .add_systems(OnEnter(GameState::GameOver), reveal_scores);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -5,6 +5,7 @@ use bevy_girk_game_fw::*;
 use bevy_girk_utils::*;
 use game_core::*;
 use renet2_setup::GameServerSetupConfig;
+use wiring_backend::{DEFAULT_GAME_SECS, DEFAULT_PREP_SECS};
 use wiring_game_instance::*;
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -15,7 +16,6 @@ pub(crate) fn make_click_game_configs() -> ClickGameFactoryConfig
 {
     // game duration
     let game_ticks_per_sec = 20;
-    let game_num_ticks = game_ticks_per_sec * 30;
 
     // versioning
     //todo: use hasher directly
@@ -23,7 +23,6 @@ pub(crate) fn make_click_game_configs() -> ClickGameFactoryConfig
 
     // config
     let max_init_ticks = game_ticks_per_sec * 5;
-    let game_prep_ticks = 0;
     let game_over_ticks = game_ticks_per_sec * 3;
 
     // server setup config
@@ -48,13 +47,45 @@ pub(crate) fn make_click_game_configs() -> ClickGameFactoryConfig
     let game_fw_config = GameFwConfig::new(game_ticks_per_sec, max_init_ticks, game_over_ticks);
 
     // game duration config
-    let duration_config = GameDurationConfig::new(game_prep_ticks, game_num_ticks);
+    // - prep and play durations are replaced by the lobby's game settings when a game launches
+    // - tied games go to sudden-death overtime for up to 10 seconds
+    let duration_config = GameDurationConfig::new(
+        DEFAULT_PREP_SECS as u32 * game_ticks_per_sec,
+        DEFAULT_GAME_SECS as u32 * game_ticks_per_sec,
+    )
+    .with_overtime(game_ticks_per_sec * 10);
+
+    // click limit config
+    // - at most 15 clicks per second
+    // - flag players whose last 20 click intervals are nearly identical
+    let click_limit_config = ClickLimitConfig::new(game_ticks_per_sec, 15, 20, 0.05);
+
+    // pause config
+    // - pauses need a majority vote
+    // - each player can start 2 pauses, which resume automatically after 30 seconds
+    let pause_config = PauseConfig::new(PauseMode::Vote, 2, game_ticks_per_sec * 30);
+
+    // chat config
+    // - messages up to 200 characters
+    // - at most 5 messages every 10 seconds
+    // - watchers can only chat with other watchers
+    let chat_config = ChatConfig::new(200, game_ticks_per_sec * 10, 5, false);
+
+    // disconnect config
+    // - disconnected players forfeit if they don't reconnect within 30 seconds
+    let disconnect_config = DisconnectConfig::new(game_ticks_per_sec * 30);
 
     // click game factory config
     let game_factory_config = ClickGameFactoryConfig {
         server_setup_config,
         game_fw_config,
         duration_config,
+        game_mode: GameMode::Timed,
+        click_limit_config,
+        pause_config,
+        chat_config,
+        disconnect_config,
+        replay_dir: None,
         resend_time: Duration::from_millis(300),
     };
 
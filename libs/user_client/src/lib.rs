@@ -1,15 +1,19 @@
 mod game;
 mod host_client;
 mod lobbies;
+mod lobby_control;
 mod pending_request;
 mod plugin;
+mod settings;
 mod timer_configs;
 mod ui;
 
 pub(crate) use game::*;
 pub use host_client::*;
 pub(crate) use lobbies::*;
+pub use lobby_control::*;
 pub(crate) use pending_request::*;
 pub use plugin::*;
+pub use settings::*;
 pub use timer_configs::*;
 pub(crate) use ui::*;
@@ -67,12 +67,19 @@ fn set_ack_request_data(
     mut c: Commands,
     time: Res<Time>,
     mut ack_request: ReactResMut<AckRequestData>,
+    ready: ReactRes<LobbyReady>,
 )
 {
     let ack_req = event.read();
     ack_request
         .get_mut(&mut c)
         .set(ack_req.lobby_id, time.elapsed());
+
+    // users that are ready don't need to be asked
+    if ready.is_ready(ack_req.lobby_id) {
+        tracing::trace!(ack_req.lobby_id, "auto-acking lobby because we are ready");
+        c.syscall((), send_lobby_ack);
+    }
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,66 @@
+use std::collections::HashSet;
+
+use bevy::prelude::*;
+use wiring_backend::MAX_LOBBY_BANS;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Users banned from lobbies made by this user.
+///
+/// Bans are sent with each new lobby and checked by the host server when users try to join. Ban changes are also
+/// sent to the lobby control server so they apply to the current lobby.
+#[derive(Resource, Default, Debug, Clone)]
+pub(crate) struct LobbyBans
+{
+    users: HashSet<u128>,
+}
+
+impl LobbyBans
+{
+    /// Makes a ban list. Bans past [`MAX_LOBBY_BANS`] are dropped.
+    pub(crate) fn new(users: HashSet<u128>) -> Self
+    {
+        Self { users: users.into_iter().take(MAX_LOBBY_BANS).collect() }
+    }
+
+    pub(crate) fn contains(&self, user_id: u128) -> bool
+    {
+        self.users.contains(&user_id)
+    }
+
+    /// Bans a user.
+    ///
+    /// Returns `false` if the ban list is full.
+    pub(crate) fn ban(&mut self, user_id: u128) -> bool
+    {
+        if self.users.len() >= MAX_LOBBY_BANS {
+            return false;
+        }
+        self.users.insert(user_id);
+        true
+    }
+
+    pub(crate) fn unban(&mut self, user_id: u128)
+    {
+        self.users.remove(&user_id);
+    }
+
+    pub(crate) fn users(&self) -> &HashSet<u128>
+    {
+        &self.users
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(super) struct LobbyBansPlugin;
+
+impl Plugin for LobbyBansPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.init_resource::<LobbyBans>();
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
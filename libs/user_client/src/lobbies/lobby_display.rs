@@ -3,6 +3,7 @@ use bevy_cobweb::prelude::*;
 use bevy_girk_backend_public::*;
 use bevy_girk_client_fw::ClientFwConfig;
 use bevy_girk_client_instance::ClientInstanceCommand;
+use game_core::PlayerHandicap;
 use wiring_backend::*;
 
 use crate::*;
@@ -197,6 +198,22 @@ impl LobbyDisplay
     {
         self.lobby_type == Some(LobbyType::Hosted)
     }
+
+    /// Sets a player's handicap in a local lobby.
+    ///
+    /// Handicaps in hosted lobbies are managed by the lobby control server (see [`LobbyMembers`]).
+    pub(crate) fn set_local_handicap(&mut self, member_id: u128, handicap: PlayerHandicap)
+    {
+        if !self.is_local() {
+            return;
+        }
+        let Some(contents) = self.current.as_mut() else { return };
+
+        match handicap.is_none() {
+            true => contents.config.handicaps.remove(&member_id),
+            false => contents.config.handicaps.insert(member_id, handicap),
+        };
+    }
 }
 
 //-------------------------------------------------------------------------------------------------------------------
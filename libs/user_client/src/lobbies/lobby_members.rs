@@ -0,0 +1,220 @@
+use bevy::prelude::*;
+use bevy_cobweb::prelude::*;
+use game_core::PlayerHandicap;
+use wiring_backend::*;
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Requests the member states of the current hosted lobby from the lobby control server.
+pub(crate) fn request_lobby_member_states(client: Res<LobbyControlClient>, lobby: ReactRes<LobbyDisplay>)
+{
+    if !lobby.is_hosted() {
+        return;
+    }
+    let Some(lobby_id) = lobby.lobby_id() else { return };
+
+    client.send(UserToLobbyControlMsg::GetMemberStates { lobby_id });
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Sends the user's chosen name to the lobby control server, which shares it with the other members of the current
+/// hosted lobby.
+pub(crate) fn send_lobby_name(
+    client: Res<LobbyControlClient>,
+    lobby: ReactRes<LobbyDisplay>,
+    name: Res<LocalPlayerName>,
+)
+{
+    if !lobby.is_hosted() {
+        return;
+    }
+    let Some(lobby_id) = lobby.lobby_id() else { return };
+
+    client.send(UserToLobbyControlMsg::SetName { lobby_id, name: name.0.clone() });
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Clears cached member states when the user leaves a lobby, and requests them when the user enters a lobby.
+///
+/// The user's ready state and name are also sent, since the lobby control server resets them when users join
+/// lobbies.
+fn sync_lobby_members(mut c: Commands, lobby: ReactRes<LobbyDisplay>, mut members: ReactResMut<LobbyMembers>)
+{
+    let lobby_id = lobby.lobby_id().filter(|_| lobby.is_hosted());
+    if members.lobby_id == lobby_id {
+        return;
+    }
+
+    members.get_mut(&mut c).reset(lobby_id);
+    if lobby_id.is_some() {
+        c.syscall((), send_lobby_ready);
+        c.syscall((), send_lobby_name);
+        c.syscall((), request_lobby_member_states);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Removes a member from the current hosted lobby. Only works for the lobby owner.
+pub(crate) fn kick_lobby_member(
+    In(member_id): In<u128>,
+    client: Res<LobbyControlClient>,
+    lobby: ReactRes<LobbyDisplay>,
+)
+{
+    if !lobby.is_hosted() {
+        tracing::warn!("ignoring kick because we aren't in a hosted lobby");
+        return;
+    }
+    let Some(lobby_id) = lobby.lobby_id() else { return };
+
+    tracing::info!(lobby_id, member_id, "kicking lobby member");
+    client.send(UserToLobbyControlMsg::Kick { lobby_id, member_id });
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Bans or unbans a user from lobbies made by this user, including the current hosted lobby.
+///
+/// Banned members of the current lobby are kicked.
+pub(crate) fn set_lobby_ban(
+    In((user_id, banned)): In<(u128, bool)>,
+    client: Res<LobbyControlClient>,
+    lobby: ReactRes<LobbyDisplay>,
+    mut bans: ResMut<LobbyBans>,
+)
+{
+    match banned {
+        true => {
+            if !bans.ban(user_id) {
+                tracing::warn!(user_id, "failed banning lobby member, ban list is full");
+                return;
+            }
+        }
+        false => bans.unban(user_id),
+    }
+
+    if !lobby.is_hosted() {
+        return;
+    }
+    let Some(lobby_id) = lobby.lobby_id() else { return };
+    client.send(UserToLobbyControlMsg::SetBanned { lobby_id, user_id, banned });
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Sets the handicap of a player in the current lobby. Only works for the lobby owner.
+pub(crate) fn set_lobby_handicap(
+    In((member_id, handicap)): In<(u128, PlayerHandicap)>,
+    mut c: Commands,
+    client: Res<LobbyControlClient>,
+    mut lobby: ReactResMut<LobbyDisplay>,
+)
+{
+    let Some(lobby_id) = lobby.lobby_id() else { return };
+    tracing::trace!(lobby_id, member_id, ?handicap, "setting lobby member handicap");
+
+    match lobby.lobby_type() {
+        Some(LobbyType::Local) => lobby.get_mut(&mut c).set_local_handicap(member_id, handicap),
+        Some(LobbyType::Hosted) => {
+            client.send(UserToLobbyControlMsg::SetHandicap { lobby_id, member_id, handicap });
+        }
+        None => (),
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Event broadcast when the lobby owner removes the user from a lobby.
+#[derive(Debug)]
+pub(crate) struct KickedFromLobby
+{
+    pub(crate) lobby_id: u64,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Caches the [`LobbyMemberStates`] of the current hosted lobby.
+///
+/// This is a reactive resource.
+#[derive(ReactResource, Default, Debug)]
+pub(crate) struct LobbyMembers
+{
+    /// The current hosted lobby.
+    lobby_id: Option<u64>,
+    /// Member states of the current lobby, once they are received.
+    states: Option<LobbyMemberStates>,
+}
+
+impl LobbyMembers
+{
+    /// Changes the current lobby and discards the cached member states.
+    pub(crate) fn reset(&mut self, lobby_id: Option<u64>)
+    {
+        self.lobby_id = lobby_id;
+        self.states = None;
+    }
+
+    pub(crate) fn set_states(&mut self, states: LobbyMemberStates)
+    {
+        self.states = Some(states);
+    }
+
+    pub(crate) fn lobby_id(&self) -> Option<u64>
+    {
+        self.lobby_id
+    }
+
+    /// Gets the member states of a lobby if they are known.
+    pub(crate) fn get(&self, lobby_id: u64) -> Option<&LobbyMemberStates>
+    {
+        if self.lobby_id != Some(lobby_id) {
+            return None;
+        }
+        self.states.as_ref()
+    }
+
+    /// Gets a member's display name, or an abbreviated user id if the member hasn't chosen a name.
+    ///
+    /// Only the owner's name is known if member states haven't been received (e.g. in local lobbies).
+    pub(crate) fn label(&self, lobby: &ClickLobbyContents, member_id: u128) -> String
+    {
+        let name = match self.get(lobby.id) {
+            Some(states) => states.name(member_id).cloned(),
+            None if member_id == lobby.owner_id => lobby.config.owner_name.clone(),
+            None => None,
+        };
+        name.unwrap_or_else(|| format!("{:0>6}", member_id % 1_000_000u128))
+    }
+
+    /// Gets a player's handicap in the displayed lobby.
+    ///
+    /// Falls back to the lobby config if member states haven't been received (e.g. in local lobbies).
+    pub(crate) fn handicap(&self, lobby: &ClickLobbyContents, member_id: u128) -> PlayerHandicap
+    {
+        match self.get(lobby.id) {
+            Some(states) => states.handicap(member_id),
+            None => lobby.config.handicaps.get(&member_id).copied().unwrap_or_default(),
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(super) struct LobbyMembersPlugin;
+
+impl Plugin for LobbyMembersPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.init_react_resource::<LobbyMembers>()
+            .add_reactor(resource_mutation::<LobbyDisplay>(), sync_lobby_members)
+            .add_systems(Update, send_lobby_name.run_if(resource_changed::<LocalPlayerName>));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
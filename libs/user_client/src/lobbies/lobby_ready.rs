@@ -0,0 +1,91 @@
+use bevy::prelude::*;
+use bevy_cobweb::prelude::*;
+use wiring_backend::*;
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Sends the user's ready state in their current hosted lobby to the lobby control server.
+pub(crate) fn send_lobby_ready(
+    client: Res<LobbyControlClient>,
+    lobby: ReactRes<LobbyDisplay>,
+    ready: ReactRes<LobbyReady>,
+)
+{
+    if !lobby.is_hosted() {
+        return;
+    }
+    let Some(lobby_id) = lobby.lobby_id() else { return };
+
+    client.send(UserToLobbyControlMsg::SetReady { lobby_id, ready: ready.is_ready(lobby_id) });
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Toggles whether the user is ready to play in their current hosted lobby.
+pub(crate) fn toggle_lobby_ready(
+    mut c: Commands,
+    lobby: ReactRes<LobbyDisplay>,
+    mut ready: ReactResMut<LobbyReady>,
+)
+{
+    if !lobby.is_hosted() {
+        tracing::warn!("ignoring ready toggle because we aren't in a hosted lobby");
+        return;
+    }
+    let Some(lobby_id) = lobby.lobby_id() else { return };
+
+    let is_ready = ready.is_ready(lobby_id);
+    tracing::trace!(lobby_id, ready = !is_ready, "toggling lobby ready");
+    ready.get_mut(&mut c).set(lobby_id, !is_ready);
+    c.syscall((), send_lobby_ready);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Tracks if the user is ready to play in a hosted lobby.
+///
+/// Ready states are sent to the lobby control server, which shares them with the other lobby members (see
+/// [`LobbyMembers`]). Lobbies can only launch when all players are ready.
+///
+/// When the lobby owner launches a lobby, the host server asks every member to acknowledge the launch (see
+/// [`AckRequestData`]). Ready users acknowledge automatically, so the launch doesn't wait on them.
+///
+/// This is a reactive resource.
+#[derive(ReactResource, Default, Debug)]
+pub(crate) struct LobbyReady
+{
+    /// The lobby the user is ready in.
+    lobby_id: Option<u64>,
+}
+
+impl LobbyReady
+{
+    pub(crate) fn set(&mut self, lobby_id: u64, ready: bool)
+    {
+        match ready {
+            true => self.lobby_id = Some(lobby_id),
+            false => self.lobby_id = None,
+        }
+    }
+
+    pub(crate) fn is_ready(&self, lobby_id: u64) -> bool
+    {
+        self.lobby_id == Some(lobby_id)
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(super) struct LobbyReadyPlugin;
+
+impl Plugin for LobbyReadyPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.init_react_resource::<LobbyReady>();
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -1,9 +1,14 @@
+use std::collections::{HashMap, HashSet};
+
 use bevy::prelude::*;
 use bevy_cobweb::prelude::*;
 use bevy_girk_backend_public::{HostUserClient, UserToHostRequest};
 use bevy_girk_utils::ser_msg;
 use renet2_setup::ConnectionType;
-use wiring_backend::{ClickLobbyConfig, ClickLobbyContents, ClickLobbyMemberType};
+use wiring_backend::{
+    ClickLobbyConfig, ClickLobbyContents, ClickLobbyGameMode, ClickLobbyMemberType, DEFAULT_GAME_SECS,
+    DEFAULT_PREP_SECS,
+};
 
 use crate::*;
 
@@ -12,16 +17,16 @@ use crate::*;
 /// Convert state to lobby contents.
 ///
 /// Panics if not single-player.
-fn single_player_lobby(owner_id: u128, data: &MakeLobbyData) -> ClickLobbyContents
+fn single_player_lobby(owner_id: u128, config: ClickLobbyConfig) -> ClickLobbyContents
 {
-    if !data.is_single_player() {
+    if !config.is_single_player() {
         panic!("cannot convert make lobby data to lobby contents for multiplayer lobbies");
     }
 
     ClickLobbyContents {
         id: 0u64,
         owner_id,
-        config: data.config.clone(),
+        config,
         players: vec![(ConnectionType::Memory, owner_id)], // Must use memory connection type
         watchers: vec![],
     }
@@ -35,6 +40,8 @@ fn make_local_lobby(
     make_lobby: PendingRequestParam<MakeLobby>,
     mut lobby_display: ReactResMut<LobbyDisplay>,
     data: ReactRes<MakeLobbyData>,
+    owner_name: Res<LocalPlayerName>,
+    bans: Res<LobbyBans>,
 )
 {
     // do nothing if there is a pending request
@@ -54,7 +61,7 @@ fn make_local_lobby(
     tracing::trace!(?data.member_type, ?data.config, "making a local lobby");
     lobby_display
         .get_mut(&mut c)
-        .set(single_player_lobby(client.id(), &data), LobbyType::Local);
+        .set(single_player_lobby(client.id(), data.config_with_owner(&owner_name, &bans)), LobbyType::Local);
 
     // send event for UI updates
     c.react().broadcast(MadeLocalLobby);
@@ -67,6 +74,8 @@ fn send_make_lobby_request(
     client: Res<HostUserClient>,
     make_lobby: PendingRequestParam<MakeLobby>,
     data: ReactRes<MakeLobbyData>,
+    owner_name: Res<LocalPlayerName>,
+    bans: Res<LobbyBans>,
 )
 {
     // get request entity
@@ -83,7 +92,7 @@ fn send_make_lobby_request(
     let new_req = client.request(UserToHostRequest::MakeLobby {
         mcolor: data.member_type.into(),
         pwd: data.pwd.clone(),
-        data: ser_msg(&data.config),
+        data: ser_msg(&data.config_with_owner(&owner_name, &bans)),
     });
 
     // save request
@@ -123,6 +132,16 @@ impl MakeLobbyData
     {
         self.config.is_single_player()
     }
+
+    /// Gets the lobby config with the owner's current player name and ban list.
+    fn config_with_owner(&self, owner_name: &LocalPlayerName, bans: &LobbyBans) -> ClickLobbyConfig
+    {
+        ClickLobbyConfig {
+            owner_name: owner_name.0.clone(),
+            banned_users: bans.users().clone(),
+            ..self.config.clone()
+        }
+    }
 }
 
 impl Default for MakeLobbyData
@@ -132,7 +151,20 @@ impl Default for MakeLobbyData
         Self {
             member_type: ClickLobbyMemberType::Player,
             pwd: String::default(),
-            config: ClickLobbyConfig { max_players: 1, max_watchers: 0 },
+            config: ClickLobbyConfig {
+                max_players: 1,
+                max_watchers: 0,
+                num_teams: 0,
+                bot_difficulty: None,
+                hidden_scores: false,
+                // handicaps are set in the lobby display once players have joined
+                handicaps: HashMap::default(),
+                prep_secs: DEFAULT_PREP_SECS,
+                game_secs: DEFAULT_GAME_SECS,
+                game_mode: ClickLobbyGameMode::default(),
+                owner_name: None,
+                banned_users: HashSet::default(),
+            },
         }
     }
 }
@@ -1,15 +1,21 @@
 mod ack_request;
 mod join_lobby;
+mod lobby_bans;
 mod lobby_display;
 mod lobby_list;
+mod lobby_members;
 mod lobby_page;
+mod lobby_ready;
 mod make_lobby;
 mod plugin;
 
 pub(crate) use ack_request::*;
 pub(crate) use join_lobby::*;
+pub(crate) use lobby_bans::*;
 pub(crate) use lobby_display::*;
 pub(crate) use lobby_list::*;
+pub(crate) use lobby_members::*;
 pub(crate) use lobby_page::*;
+pub(crate) use lobby_ready::*;
 pub(crate) use make_lobby::*;
 pub(crate) use plugin::*;
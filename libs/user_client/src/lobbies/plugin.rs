@@ -40,9 +40,12 @@ impl Plugin for LobbiesPlugin
     fn build(&self, app: &mut App)
     {
         app.add_plugins(AckRequestPlugin)
+            .add_plugins(LobbyBansPlugin)
             .add_plugins(LobbyDisplayPlugin)
             .add_plugins(LobbyPagePlugin)
+            .add_plugins(LobbyReadyPlugin)
             .add_plugins(LobbyListPlugin)
+            .add_plugins(LobbyMembersPlugin)
             .add_plugins(JoinLobbyPlugin)
             .add_plugins(MakeLobbyPlugin)
             .add_systems(PreStartup, setup_request_entities);
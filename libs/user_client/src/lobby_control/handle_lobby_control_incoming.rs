@@ -0,0 +1,101 @@
+use bevy::prelude::*;
+use bevy_cobweb::prelude::*;
+use bevy_simplenet::ClientReport;
+use wiring_backend::*;
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn handle_lobby_control_report(In(report): In<ClientReport>, mut c: Commands)
+{
+    tracing::debug!(?report, "lobby control connection report");
+    let ClientReport::Connected = report else { return };
+
+    // member states may have changed while we were disconnected
+    c.syscall((), send_lobby_ready);
+    c.syscall((), send_lobby_name);
+    c.syscall((), request_lobby_member_states);
+    c.syscall((), request_player_ratings);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn handle_member_states(
+    In((lobby_id, states)): In<(u64, LobbyMemberStates)>,
+    mut c: Commands,
+    mut members: ReactResMut<LobbyMembers>,
+)
+{
+    if members.lobby_id() != Some(lobby_id) {
+        tracing::debug!(lobby_id, "ignoring member states for unknown lobby");
+        return;
+    }
+
+    members.get_mut(&mut c).set_states(states);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn handle_ratings(
+    In(ratings): In<Vec<(u128, PlayerRating)>>,
+    mut c: Commands,
+    mut cache: ReactResMut<PlayerRatingsCache>,
+)
+{
+    cache.get_mut(&mut c).update(ratings);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn handle_kicked(In(lobby_id): In<u64>, mut c: Commands, lobby: ReactRes<LobbyDisplay>)
+{
+    if lobby.lobby_id() != Some(lobby_id) || !lobby.is_hosted() {
+        tracing::debug!(lobby_id, "ignoring kick from a lobby we aren't in");
+        return;
+    }
+    tracing::info!(lobby_id, "kicked from lobby");
+
+    // the lobby owner can't launch until we leave
+    c.syscall((), leave_current_lobby);
+    c.react().broadcast(KickedFromLobby { lobby_id });
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn handle_lobby_control_incoming(w: &mut World)
+{
+    while let Some(client_event) = w.resource_mut::<LobbyControlClient>().next() {
+        match client_event {
+            LobbyControlClientEvent::Report(report) => w.syscall(report, handle_lobby_control_report),
+            LobbyControlClientEvent::Msg(msg) => match msg {
+                LobbyControlToUserMsg::MemberStates { lobby_id, states } => {
+                    w.syscall((lobby_id, states), handle_member_states);
+                }
+                LobbyControlToUserMsg::Kicked { lobby_id } => w.syscall(lobby_id, handle_kicked),
+                LobbyControlToUserMsg::Ratings { ratings } => w.syscall(ratings, handle_ratings),
+            },
+            // the lobby control channel doesn't use requests
+            event => tracing::debug!(?event, "ignoring unexpected lobby control event"),
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(SystemSet, Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub(super) struct HandleLobbyControlIncomingSet;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(super) struct LobbyControlIncomingPlugin;
+
+impl Plugin for LobbyControlIncomingPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.add_systems(First, handle_lobby_control_incoming.in_set(HandleLobbyControlIncomingSet));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
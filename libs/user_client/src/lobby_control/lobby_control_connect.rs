@@ -0,0 +1,77 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::time::common_conditions::on_timer;
+use wiring_backend::LobbyControlClient;
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn try_reconnect_lobby_control(
+    mut c: Commands,
+    constructor: Res<LobbyControlClientConstructor>,
+    client: Option<Res<LobbyControlClient>>,
+)
+{
+    if client.map(|client| !client.is_dead()).unwrap_or(false) {
+        return;
+    }
+
+    tracing::info!("Constructing new lobby control client...");
+    c.insert_resource(constructor.new_client());
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Stores a callback that produces [`LobbyControlClient`] on request.
+///
+/// The lobby control client manages live lobby state (e.g. bans) that the host server doesn't support. It must use
+/// the same client id as the [`HostUserClient`](bevy_girk_backend_public::HostUserClient).
+#[derive(Resource)]
+pub struct LobbyControlClientConstructor
+{
+    callback: Box<dyn Fn() -> LobbyControlClient + Send + Sync + 'static>,
+}
+
+impl LobbyControlClientConstructor
+{
+    pub fn new(callback: impl Fn() -> LobbyControlClient + Send + Sync + 'static) -> Self
+    {
+        Self { callback: Box::new(callback) }
+    }
+
+    pub fn new_client(&self) -> LobbyControlClient
+    {
+        (self.callback)()
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(SystemSet, Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub(super) struct LobbyControlConnectSet;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(super) struct LobbyControlConnectPlugin;
+
+impl Plugin for LobbyControlConnectPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        let timer_configs = app.world().resource::<TimerConfigs>();
+        let refresh = Duration::from_millis(timer_configs.host_reconstruct_loop_ms);
+
+        // Make sure there is a client resource after startup.
+        app.add_systems(Startup, try_reconnect_lobby_control)
+            .add_systems(
+                First,
+                try_reconnect_lobby_control
+                    .run_if(on_timer(refresh))
+                    .in_set(LobbyControlConnectSet),
+            );
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
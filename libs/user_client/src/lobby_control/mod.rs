@@ -0,0 +1,9 @@
+mod handle_lobby_control_incoming;
+mod lobby_control_connect;
+mod player_ratings_cache;
+mod plugin;
+
+pub(self) use handle_lobby_control_incoming::*;
+pub use lobby_control_connect::*;
+pub(crate) use player_ratings_cache::*;
+pub(super) use plugin::*;
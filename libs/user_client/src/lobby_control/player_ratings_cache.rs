@@ -0,0 +1,74 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use bevy_cobweb::prelude::*;
+use bevy_girk_backend_public::*;
+use wiring_backend::*;
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Requests the ratings of the user and the members of their current hosted lobby.
+pub(crate) fn request_player_ratings(
+    client: Res<LobbyControlClient>,
+    host_client: Res<HostUserClient>,
+    lobby: ReactRes<LobbyDisplay>,
+)
+{
+    let mut user_ids = vec![host_client.id()];
+    if let Some(contents) = lobby.get().filter(|_| lobby.is_hosted()) {
+        user_ids.extend(
+            contents
+                .players
+                .iter()
+                .chain(contents.watchers.iter())
+                .map(|(_, user_id)| *user_id),
+        );
+    }
+    user_ids.sort_unstable();
+    user_ids.dedup();
+    user_ids.truncate(MAX_RATINGS_PER_REQUEST);
+
+    client.send(UserToLobbyControlMsg::GetRatings { user_ids });
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Caches player ratings received from the lobby control server.
+///
+/// This is a reactive resource.
+#[derive(ReactResource, Default, Debug)]
+pub(crate) struct PlayerRatingsCache
+{
+    ratings: HashMap<u128, PlayerRating>,
+}
+
+impl PlayerRatingsCache
+{
+    pub(crate) fn update(&mut self, ratings: Vec<(u128, PlayerRating)>)
+    {
+        self.ratings.extend(ratings);
+    }
+
+    /// Gets a user's rating if it is known.
+    pub(crate) fn get(&self, user_id: u128) -> Option<PlayerRating>
+    {
+        self.ratings.get(&user_id).copied()
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(super) struct PlayerRatingsCachePlugin;
+
+impl Plugin for PlayerRatingsCachePlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.init_react_resource::<PlayerRatingsCache>()
+            .add_reactor(resource_mutation::<LobbyDisplay>(), request_player_ratings);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,26 @@
+use bevy::prelude::*;
+use bevy_girk_client_fw::ClientAppState;
+
+use super::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(crate) struct LobbyControlPlugin;
+
+impl Plugin for LobbyControlPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.add_plugins(LobbyControlConnectPlugin)
+            .add_plugins(LobbyControlIncomingPlugin)
+            .add_plugins(PlayerRatingsCachePlugin)
+            .configure_sets(
+                First,
+                (HandleLobbyControlIncomingSet, LobbyControlConnectSet)
+                    .chain()
+                    .run_if(not(in_state(ClientAppState::Loading))),
+            );
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -60,6 +60,9 @@ use super::*;
 /// - `ClientInstancePlugin` plugin *with* game factory for local games
 /// - [`TimerConfigs`] resource
 /// - [`HostClientConstructor`] resource
+/// - [`LobbyControlClientConstructor`] resource
+///
+/// Insert a [`UserSettingsPath`] resource to persist user settings.
 pub struct ClickUserClientPlugin;
 
 impl Plugin for ClickUserClientPlugin
@@ -67,8 +70,10 @@ impl Plugin for ClickUserClientPlugin
     fn build(&self, app: &mut App)
     {
         app.add_plugins(HostClientPlugin)
+            .add_plugins(LobbyControlPlugin)
             .add_plugins(LobbiesPlugin)
             .add_plugins(GamePlugin)
+            .add_plugins(UserSettingsPlugin)
             .add_plugins(UiPlugin);
     }
 }
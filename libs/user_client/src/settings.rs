@@ -0,0 +1,129 @@
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+use bevy::prelude::*;
+use client_core::InputBindings;
+use game_core::validate_player_name;
+use serde::{Deserialize, Serialize};
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Loads the user's settings from disk, if they were saved previously.
+fn load_user_settings(mut c: Commands, path: Res<UserSettingsPath>)
+{
+    let Some(path) = &path.0 else { return };
+    let settings = match std::fs::read_to_string(path) {
+        Ok(contents) => match serde_json::from_str::<UserSettings>(&contents) {
+            Ok(settings) => settings,
+            Err(err) => {
+                tracing::warn!(?path, ?err, "failed parsing user settings, using defaults");
+                return;
+            }
+        },
+        Err(err) => {
+            tracing::debug!(?path, ?err, "no user settings loaded");
+            return;
+        }
+    };
+
+    tracing::info!(?path, "loaded user settings");
+    c.insert_resource(settings.input_bindings);
+
+    // names may have been edited by hand, and the server won't accept invalid names
+    let player_name = settings.player_name.filter(|name| match validate_player_name(name) {
+        Ok(()) => true,
+        Err(err) => {
+            tracing::warn!(?name, %err, "ignoring invalid player name in user settings");
+            false
+        }
+    });
+    c.insert_resource(LocalPlayerName(player_name));
+    c.insert_resource(LobbyBans::new(settings.banned_users));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Saves the user's settings to disk.
+fn save_user_settings(
+    path: Res<UserSettingsPath>,
+    input_bindings: Res<InputBindings>,
+    player_name: Res<LocalPlayerName>,
+    bans: Res<LobbyBans>,
+)
+{
+    let Some(path) = &path.0 else { return };
+    let settings = UserSettings {
+        input_bindings: input_bindings.clone(),
+        player_name: player_name.0.clone(),
+        banned_users: bans.users().clone(),
+    };
+    let contents = match serde_json::to_string_pretty(&settings) {
+        Ok(contents) => contents,
+        Err(err) => {
+            tracing::error!(?err, "failed serializing user settings");
+            return;
+        }
+    };
+
+    if let Err(err) = std::fs::write(path, contents) {
+        tracing::warn!(?path, ?err, "failed saving user settings");
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// The name the local player wants to use in games.
+///
+/// The name is sent to hosted lobbies and put in the game's launch pack. Players are given a default name if this
+/// is `None`.
+#[derive(Resource, Default, Debug, Clone)]
+pub struct LocalPlayerName(pub Option<String>);
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Location of the user's settings file.
+///
+/// Settings are not persisted if there is no path (e.g. in WASM clients).
+#[derive(Resource, Default, Debug, Clone)]
+pub struct UserSettingsPath(pub Option<PathBuf>);
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// User settings that are saved between sessions.
+#[derive(Serialize, Deserialize, Default, Debug)]
+#[serde(default)]
+pub struct UserSettings
+{
+    pub input_bindings: InputBindings,
+    /// The user's display name in games. Players are given a default name if this is `None`.
+    pub player_name: Option<String>,
+    /// Users banned from lobbies made by this user.
+    pub banned_users: HashSet<u128>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Loads user settings on startup and saves them when they change.
+pub(crate) struct UserSettingsPlugin;
+
+impl Plugin for UserSettingsPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.init_resource::<UserSettingsPath>()
+            .init_resource::<LocalPlayerName>()
+            .add_systems(Startup, load_user_settings)
+            .add_systems(
+                Update,
+                save_user_settings.run_if(
+                    resource_changed::<InputBindings>
+                        .or(resource_changed::<LocalPlayerName>)
+                        .or(resource_changed::<LobbyBans>),
+                ),
+            );
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -1,9 +1,24 @@
 use bevy::prelude::*;
+use bevy_cobweb::prelude::*;
 use bevy_cobweb_ui::prelude::*;
+use bevy_girk_backend_public::*;
+
+use crate::*;
 
 //-------------------------------------------------------------------------------------------------------------------
 
-pub(crate) fn build_home_section(_h: &mut UiSceneHandle) {}
+pub(crate) fn build_home_section(h: &mut UiSceneHandle)
+{
+    h.get("rating::text").update_on(
+        resource_mutation::<PlayerRatingsCache>(),
+        |id: TargetId, mut e: TextEditor, ratings: ReactRes<PlayerRatingsCache>, client: Res<HostUserClient>| {
+            match ratings.get(client.id()) {
+                Some(rating) => write_text!(e, *id, "Rating: {:.0} ({} games)", rating.rating, rating.games),
+                None => write_text!(e, *id, "Rating: unknown"),
+            };
+        },
+    );
+}
 
 //-------------------------------------------------------------------------------------------------------------------
 
@@ -0,0 +1,41 @@
+use bevy::prelude::*;
+use bevy_cobweb::prelude::*;
+use bevy_cobweb_ui::prelude::*;
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn build_kicked_popup(event: &KickedFromLobby, h: &mut UiSceneHandle)
+{
+    tracing::trace!("building kicked popup");
+
+    let popup_id = h.id();
+    let mut h = h.get("window");
+
+    let lobby_id = event.lobby_id % 1_000_000u64;
+    h.get("content::text")
+        .update_text(format!("The owner removed you from lobby {:0>6}.", lobby_id));
+    h.get("footer::accept_button")
+        .on_pressed(move |mut c: Commands| {
+            c.get_entity(popup_id)?.despawn();
+            DONE
+        });
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(super) struct UiKickedPopupPlugin;
+
+impl Plugin for UiKickedPopupPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.add_reactor(
+            broadcast::<KickedFromLobby>(),
+            setup_broadcast_popup(("ui.user.sections.play", "kicked_popup"), build_kicked_popup),
+        );
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -2,21 +2,135 @@ use bevy::prelude::*;
 use bevy_cobweb::prelude::*;
 use bevy_cobweb_ui::prelude::*;
 use bevy_girk_backend_public::*;
+use game_core::PlayerHandicap;
 use wiring_backend::*;
 
 use crate::*;
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Amount the click cooldown handicap changes by each time its button is pressed.
+const HANDICAP_COOLDOWN_STEP_TICKS: u32 = 5;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Default)]
+struct RefreshLobbyBans;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Shows handicaps like the game scoreboard, as points per click (e.g. `x2`) and click cooldown in ticks
+/// (e.g. `cd 5`).
+fn handicap_tag(handicap: PlayerHandicap) -> String
+{
+    if handicap.is_none() {
+        return String::default();
+    }
+
+    let mut parts = Vec::default();
+    if handicap.points_per_click != 1 {
+        parts.push(format!("x{}", handicap.points_per_click));
+    }
+    if handicap.click_cooldown_ticks > 0 {
+        parts.push(format!("cd {}", handicap.click_cooldown_ticks));
+    }
+    format!(" [{}]", parts.join(" "))
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Sets up the handicap buttons of a player. Only the lobby owner can set handicaps.
+///
+/// Each button cycles through the allowed values of one part of the handicap.
+fn edit_handicap_buttons(mut h: UiSceneHandle, owner_id: u128, member_id: u128, handicap: PlayerHandicap)
+{
+    h.update(move |id: TargetId, mut c: Commands, client: Res<HostUserClient>| {
+        if client.id() == owner_id {
+            return;
+        }
+        let Ok(mut ec) = c.get_entity(*id) else { return };
+        ec.apply(DisplayControl::Hide);
+    });
+
+    h.edit("points_button", |h| {
+        h.get("text")
+            .update_text(format!("x{}", handicap.points_per_click));
+        h.on_pressed(move |mut c: Commands| {
+            let points_per_click = handicap.points_per_click % MAX_POINTS_PER_CLICK + 1;
+            c.syscall((member_id, PlayerHandicap { points_per_click, ..handicap }), set_lobby_handicap);
+        });
+    });
+    h.edit("cooldown_button", |h| {
+        h.get("text")
+            .update_text(format!("cd {}", handicap.click_cooldown_ticks));
+        h.on_pressed(move |mut c: Commands| {
+            let mut click_cooldown_ticks = handicap.click_cooldown_ticks + HANDICAP_COOLDOWN_STEP_TICKS;
+            if click_cooldown_ticks > MAX_CLICK_COOLDOWN_TICKS {
+                click_cooldown_ticks = 0;
+            }
+            c.syscall((member_id, PlayerHandicap { click_cooldown_ticks, ..handicap }), set_lobby_handicap);
+        });
+    });
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Sets up the kick button of a lobby member. Only the owner of a hosted lobby can kick other members.
+fn edit_kick_button(mut h: UiSceneHandle, owner_id: u128, member_id: u128)
+{
+    h.update(
+        move |id: TargetId, mut c: Commands, client: Res<HostUserClient>, display: ReactRes<LobbyDisplay>| {
+            if client.id() == owner_id && member_id != owner_id && display.is_hosted() {
+                return;
+            }
+            let Ok(mut ec) = c.get_entity(*id) else { return };
+            ec.apply(DisplayControl::Hide);
+        },
+    );
+    h.on_pressed(move |mut c: Commands| {
+        c.syscall(member_id, kick_lobby_member);
+    });
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Sets up the ban toggle of a lobby member. Only the lobby owner can ban other members.
+///
+/// Banning a member of a hosted lobby kicks them.
+fn edit_ban_button(mut h: UiSceneHandle, owner_id: u128, member_id: u128)
+{
+    h.update(move |id: TargetId, mut c: Commands, client: Res<HostUserClient>| {
+        if client.id() == owner_id && member_id != owner_id {
+            return;
+        }
+        let Ok(mut ec) = c.get_entity(*id) else { return };
+        ec.apply(DisplayControl::Hide);
+    });
+    h.get("text").update_on(
+        broadcast::<RefreshLobbyBans>(),
+        move |id: TargetId, mut e: TextEditor, bans: Res<LobbyBans>| {
+            match bans.contains(member_id) {
+                true => write_text!(e, *id, "Unban"),
+                false => write_text!(e, *id, "Ban"),
+            };
+        },
+    );
+    h.on_pressed(move |mut c: Commands, bans: Res<LobbyBans>| {
+        c.syscall((member_id, !bans.contains(member_id)), set_lobby_ban);
+    });
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 pub(super) fn build_lobby_display(h: &mut UiSceneHandle)
 {
     h.get("header::lobby_info::text").update_on(
-        resource_mutation::<LobbyDisplay>(),
-        |id: TargetId, mut e: TextEditor, display: ReactRes<LobbyDisplay>| {
+        (resource_mutation::<LobbyDisplay>(), resource_mutation::<LobbyMembers>()),
+        |id: TargetId, mut e: TextEditor, display: ReactRes<LobbyDisplay>, members: ReactRes<LobbyMembers>| {
             let lobby_contents = display.get().result()?;
             let lobby_id = lobby_contents.id % 1_000_000u64;
-            let owner_id = lobby_contents.owner_id % 1_000_000u128;
-            write_text!(e, *id, "Lobby: {:0>6} -- Owner: {:0>6}", lobby_id, owner_id);
+            let owner = members.label(lobby_contents, lobby_contents.owner_id);
+            write_text!(e, *id, "Lobby: {:0>6} -- Owner: {}", lobby_id, owner);
             OK
         },
     );
@@ -42,24 +156,67 @@ pub(super) fn build_lobby_display(h: &mut UiSceneHandle)
     );
 
     h.get("content::member_list::view::shim").update_on(
-        resource_mutation::<LobbyDisplay>(),
-        |id: TargetId, mut c: Commands, mut s: SceneBuilder, display: ReactRes<LobbyDisplay>| {
+        (
+            resource_mutation::<LobbyDisplay>(),
+            resource_mutation::<LobbyMembers>(),
+            resource_mutation::<PlayerRatingsCache>(),
+        ),
+        |//
+            id: TargetId,
+            mut c: Commands,
+            mut s: SceneBuilder,
+            display: ReactRes<LobbyDisplay>,
+            members: ReactRes<LobbyMembers>,
+            ratings: ReactRes<PlayerRatingsCache>,
+            //
+        |
+        {
             // clean up previous members list
             c.get_entity(*id)?.despawn_related::<Children>();
 
             let lobby_content = display.get().result()?;
+            let owner_id = lobby_content.owner_id;
+            let states = members.get(lobby_content.id);
+            let ready_tag = |member_id: u128| match states.map(|s| s.is_ready(member_id)).unwrap_or(false) {
+                true => " (ready)",
+                false => "",
+            };
+            let rating_tag = |member_id: u128| match ratings.get(member_id) {
+                Some(rating) => format!(" ({:.0})", rating.rating),
+                None => String::default(),
+            };
             for (_, player_id) in lobby_content.players.iter() {
+                let handicap = members.handicap(lobby_content, *player_id);
                 c.ui_builder(*id)
                     .spawn_scene(("ui.user.sections.play", "lobby_display_member"), &mut s, |h| {
-                        h.get("text")
-                            .update_text(format!("Player: {:0>6}", player_id % 1_000_000u128));
+                        h.get("text").update_text(format!(
+                            "Player: {}{}{}{}",
+                            members.label(lobby_content, *player_id),
+                            rating_tag(*player_id),
+                            ready_tag(*player_id),
+                            handicap_tag(handicap)
+                        ));
+                        edit_handicap_buttons(h.get("handicap"), owner_id, *player_id, handicap);
+                        edit_kick_button(h.get("kick_button"), owner_id, *player_id);
+                        edit_ban_button(h.get("ban_button"), owner_id, *player_id);
                     });
             }
             for (_, watcher_id) in lobby_content.watchers.iter() {
                 c.ui_builder(*id)
                     .spawn_scene(("ui.user.sections.play", "lobby_display_member"), &mut s, |h| {
-                        h.get("text")
-                            .update_text(format!("Watcher: {:0>6}", watcher_id % 1_000_000u128));
+                        h.get("text").update_text(format!(
+                            "Watcher: {}{}{}",
+                            members.label(lobby_content, *watcher_id),
+                            rating_tag(*watcher_id),
+                            ready_tag(*watcher_id)
+                        ));
+                        // watchers don't have handicaps
+                        h.get("handicap").update(|id: TargetId, mut c: Commands| {
+                            let Ok(mut ec) = c.get_entity(*id) else { return };
+                            ec.apply(DisplayControl::Hide);
+                        });
+                        edit_kick_button(h.get("kick_button"), owner_id, *watcher_id);
+                        edit_ban_button(h.get("ban_button"), owner_id, *watcher_id);
                     });
             }
 
@@ -75,15 +232,45 @@ pub(super) fn build_lobby_display(h: &mut UiSceneHandle)
         )
         .on_pressed(leave_current_lobby);
     });
+    // Ready toggle. Lobbies can only launch when all players are ready, and ready users automatically accept the
+    // lobby launch.
+    h.edit("footer::ready_button", |h| {
+        h.enable_if(
+            resource_mutation::<LobbyDisplay>(),
+            |_: TargetId, display: ReactRes<LobbyDisplay>| display.is_hosted(),
+        )
+        .on_pressed(toggle_lobby_ready);
+        h.get("text").update_on(
+            (resource_mutation::<LobbyDisplay>(), resource_mutation::<LobbyReady>()),
+            |id: TargetId, mut e: TextEditor, display: ReactRes<LobbyDisplay>, ready: ReactRes<LobbyReady>| {
+                let is_ready = display.lobby_id().map(|lobby_id| ready.is_ready(lobby_id)).unwrap_or(false);
+                match is_ready {
+                    true => write_text!(e, *id, "Ready"),
+                    false => write_text!(e, *id, "Not Ready"),
+                };
+            },
+        );
+    });
     h.edit("footer::start_button", |h| {
         setup_request_tracker::<LaunchLobby>(h);
         h.enable_if(
-            resource_mutation::<LobbyDisplay>(),
-            |_: TargetId, display: ReactRes<LobbyDisplay>, client: Res<HostUserClient>| match display.get() {
+            (resource_mutation::<LobbyDisplay>(), resource_mutation::<LobbyMembers>()),
+            |//
+                _: TargetId,
+                display: ReactRes<LobbyDisplay>,
+                members: ReactRes<LobbyMembers>,
+                client: Res<HostUserClient>,
+                //
+            | match display.get() {
                 Some(data) => {
                     let owns = data.owner_id == client.id();
                     let single_player = display.is_local();
-                    let can_launch_hosted = data.can_launch_hosted();
+                    // all players must be ready
+                    let all_ready = members
+                        .get(data.id)
+                        .map(|states| data.players.iter().all(|(_, player_id)| states.is_ready(*player_id)))
+                        .unwrap_or(false);
+                    let can_launch_hosted = data.can_launch_hosted() && all_ready;
 
                     owns && (single_player || can_launch_hosted)
                 }
@@ -100,7 +287,10 @@ pub(crate) struct UiLobbyDisplayPlugin;
 
 impl Plugin for UiLobbyDisplayPlugin
 {
-    fn build(&self, _app: &mut App) {}
+    fn build(&self, app: &mut App)
+    {
+        app.add_systems(Update, broadcast_system::<RefreshLobbyBans>.run_if(resource_changed::<LobbyBans>));
+    }
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -41,8 +41,7 @@ pub(super) fn build_lobby_list(h: &mut UiSceneHandle)
                         ("ui.user.sections.play", "lobby_list_entry_owner"),
                         &mut s,
                         |h| {
-                            h.get("text")
-                                .update_text(format!("{:0>6}", lobby.owner_id % 1_000_000u128));
+                            h.get("text").update_text(lobby.owner_label());
                         },
                     );
                     c.ui_builder(*id).spawn_scene(
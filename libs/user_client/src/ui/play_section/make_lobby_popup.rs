@@ -1,12 +1,30 @@
 use bevy::prelude::*;
 use bevy_cobweb::prelude::*;
 use bevy_cobweb_ui::prelude::*;
-use wiring_backend::MAX_LOBBY_PLAYERS;
+use wiring_backend::{
+    ClickLobbyConfig, ClickLobbyGameMode, MAX_GAME_SECS, MAX_LOBBY_PLAYERS, MAX_PREP_SECS, MIN_GAME_SECS,
+    MIN_ROUND_SECS,
+};
 
 use crate::*;
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Game duration increment used by the duration buttons.
+const GAME_SECS_STEP: u16 = 10;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Shortens elimination rounds so at least two rounds fit in the game duration.
+fn fit_rounds_to_duration(config: &mut ClickLobbyConfig)
+{
+    if let ClickLobbyGameMode::Elimination { round_secs } = &mut config.game_mode {
+        *round_secs = (*round_secs).min(config.game_secs / 2).max(MIN_ROUND_SECS);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 pub(super) fn build_make_lobby_popup(_: &ActivateMakeLobbyPopup, h: &mut UiSceneHandle)
 {
     tracing::trace!("building make lobby popup");
@@ -73,6 +91,83 @@ pub(super) fn build_make_lobby_popup(_: &ActivateMakeLobbyPopup, h: &mut UiScene
                 |_: TargetId, data: ReactRes<MakeLobbyData>| data.config.max_players > 1,
             );
     });
+    h.edit("content::grid::prep_field", |h| {
+        h.get("text").update_on(
+            resource_mutation::<MakeLobbyData>(),
+            |id: TargetId, mut e: TextEditor, data: ReactRes<MakeLobbyData>| {
+                write_text!(e, *id, "{}s", data.config.prep_secs);
+            },
+        );
+        h.get("buttons::less_prep_button")
+            .on_pressed(|mut c: Commands, mut data: ReactResMut<MakeLobbyData>| {
+                let secs = data.config.prep_secs;
+                data.get_mut(&mut c).config.prep_secs = secs.saturating_sub(1);
+            })
+            .enable_if(
+                resource_mutation::<MakeLobbyData>(),
+                |_: TargetId, data: ReactRes<MakeLobbyData>| data.config.prep_secs > 0,
+            );
+        h.get("buttons::more_prep_button")
+            .on_pressed(|mut c: Commands, mut data: ReactResMut<MakeLobbyData>| {
+                let secs = data.config.prep_secs;
+                data.get_mut(&mut c).config.prep_secs = (secs + 1).min(MAX_PREP_SECS);
+            })
+            .enable_if(
+                resource_mutation::<MakeLobbyData>(),
+                |_: TargetId, data: ReactRes<MakeLobbyData>| data.config.prep_secs < MAX_PREP_SECS,
+            );
+    });
+    h.edit("content::grid::duration_field", |h| {
+        h.get("text").update_on(
+            resource_mutation::<MakeLobbyData>(),
+            |id: TargetId, mut e: TextEditor, data: ReactRes<MakeLobbyData>| {
+                write_text!(e, *id, "{}s", data.config.game_secs);
+            },
+        );
+        h.get("buttons::less_duration_button")
+            .on_pressed(|mut c: Commands, mut data: ReactResMut<MakeLobbyData>| {
+                let config = &mut data.get_mut(&mut c).config;
+                config.game_secs = config.game_secs.saturating_sub(GAME_SECS_STEP).max(MIN_GAME_SECS);
+                fit_rounds_to_duration(config);
+            })
+            .enable_if(
+                resource_mutation::<MakeLobbyData>(),
+                |_: TargetId, data: ReactRes<MakeLobbyData>| data.config.game_secs > MIN_GAME_SECS,
+            );
+        h.get("buttons::more_duration_button")
+            .on_pressed(|mut c: Commands, mut data: ReactResMut<MakeLobbyData>| {
+                let secs = data.config.game_secs;
+                data.get_mut(&mut c).config.game_secs = (secs + GAME_SECS_STEP).min(MAX_GAME_SECS);
+            })
+            .enable_if(
+                resource_mutation::<MakeLobbyData>(),
+                |_: TargetId, data: ReactRes<MakeLobbyData>| data.config.game_secs < MAX_GAME_SECS,
+            );
+    });
+    h.edit("content::grid::mode_field", |h| {
+        h.get("text").update_on(
+            resource_mutation::<MakeLobbyData>(),
+            |id: TargetId, mut e: TextEditor, data: ReactRes<MakeLobbyData>| {
+                write_text!(e, *id, "{}", data.config.game_mode.name());
+            },
+        );
+        // Modes are cycled with their default settings.
+        h.get("buttons::prev_mode_button")
+            .on_pressed(|mut c: Commands, mut data: ReactResMut<MakeLobbyData>| {
+                let num_modes = ClickLobbyGameMode::ALL.len();
+                let idx = (data.config.game_mode.index() + num_modes - 1) % num_modes;
+                let config = &mut data.get_mut(&mut c).config;
+                config.game_mode = ClickLobbyGameMode::ALL[idx];
+                fit_rounds_to_duration(config);
+            });
+        h.get("buttons::next_mode_button")
+            .on_pressed(|mut c: Commands, mut data: ReactResMut<MakeLobbyData>| {
+                let idx = (data.config.game_mode.index() + 1) % ClickLobbyGameMode::ALL.len();
+                let config = &mut data.get_mut(&mut c).config;
+                config.game_mode = ClickLobbyGameMode::ALL[idx];
+                fit_rounds_to_duration(config);
+            });
+    });
     h.edit("content::grid::join_as_field", |h| {
         h.get("text").update_text("Player");
     });
@@ -1,10 +1,12 @@
 mod join_lobby_popup;
+mod kicked_popup;
 mod lobby_display;
 mod lobby_list;
 mod make_lobby_popup;
 mod plugin;
 
 pub(crate) use join_lobby_popup::*;
+pub(crate) use kicked_popup::*;
 pub(crate) use lobby_display::*;
 pub(crate) use lobby_list::*;
 pub(crate) use make_lobby_popup::*;
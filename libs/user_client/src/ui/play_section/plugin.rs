@@ -46,6 +46,7 @@ impl Plugin for UiPlaySectionPlugin
         app.add_plugins(UiLobbyDisplayPlugin)
             .add_plugins(UiLobbyListPlugin)
             .add_plugins(UiJoinLobbyPopupPlugin)
+            .add_plugins(UiKickedPopupPlugin)
             .add_plugins(UiMakeLobbyPopupPlugin);
     }
 }
@@ -0,0 +1,124 @@
+use bevy::prelude::*;
+use bevy_cobweb::prelude::*;
+use bevy_cobweb_ui::prelude::*;
+use client_core::{InputAction, InputBinding, InputBindings};
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Default)]
+struct RefreshInputBindings;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Binds the next pressed input to the action being rebound. Press Escape to cancel.
+fn capture_rebind(
+    mut capture: ResMut<RebindCapture>,
+    mut bindings: ResMut<InputBindings>,
+    section: Res<MenuContentSection>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    gamepads: Query<&Gamepad>,
+)
+{
+    let Some(action) = capture.0 else { return };
+    if *section != MenuContentSection::Settings || keys.just_pressed(KeyCode::Escape) {
+        capture.0 = None;
+        return;
+    }
+
+    let pressed = keys
+        .get_just_pressed()
+        .map(|key| InputBinding::Key(*key))
+        .chain(mouse.get_just_pressed().map(|button| InputBinding::Mouse(*button)))
+        .chain(
+            gamepads
+                .iter()
+                .flat_map(|gamepad| gamepad.get_just_pressed().map(|button| InputBinding::Gamepad(*button))),
+        )
+        .find(|binding| binding.is_allowed());
+    let Some(binding) = pressed else { return };
+
+    tracing::debug!(?binding, ?action, "rebinding input");
+    bindings.bind(binding, action);
+    capture.0 = None;
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Sets up a row for rebinding the inputs of an action.
+fn build_binding_row(h: &mut UiSceneHandle, action: InputAction)
+{
+    h.get("action").update_text(action.name());
+    h.get("bound").update_on(
+        broadcast::<RefreshInputBindings>(),
+        move |id: TargetId, mut e: TextEditor, bindings: Res<InputBindings>, capture: Res<RebindCapture>| {
+            if capture.0 == Some(action) {
+                write_text!(e, *id, "Press a key or button (Esc to cancel)...");
+                return;
+            }
+
+            let bound = bindings
+                .get(action)
+                .map(|binding| binding.to_string())
+                .collect::<Vec<_>>();
+            match bound.is_empty() {
+                true => write_text!(e, *id, "(unbound)"),
+                false => write_text!(e, *id, "{}", bound.join(", ")),
+            };
+        },
+    );
+
+    h.get("add_button")
+        .on_pressed(move |mut capture: ResMut<RebindCapture>| {
+            capture.0 = Some(action);
+        });
+    h.get("clear_button")
+        .on_pressed(move |mut bindings: ResMut<InputBindings>| {
+            bindings.clear(action);
+        });
+    h.get("reset_button")
+        .on_pressed(move |mut bindings: ResMut<InputBindings>| {
+            bindings.reset(action);
+        });
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Adds a rebinding row for each [`InputAction`].
+pub(super) fn build_input_bindings(h: &mut UiSceneHandle)
+{
+    let mut rows = h.get("bindings");
+    for action in InputAction::ALL {
+        rows.spawn_scene(("ui.user.sections.settings", "binding_row"), |h| build_binding_row(h, action));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// The action waiting for a new input binding.
+#[derive(Resource, Default, Debug)]
+struct RebindCapture(Option<InputAction>);
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(super) struct UiInputBindingsPlugin;
+
+impl Plugin for UiInputBindingsPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.init_resource::<RebindCapture>().add_systems(
+            Update,
+            (
+                capture_rebind,
+                broadcast_system::<RefreshInputBindings>
+                    .run_if(resource_changed::<InputBindings>.or(resource_changed::<RebindCapture>)),
+            )
+                .chain(),
+        );
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
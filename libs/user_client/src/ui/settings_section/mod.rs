@@ -1,3 +1,7 @@
+mod input_bindings;
+mod player_name;
 mod plugin;
 
+pub(self) use input_bindings::*;
+pub(self) use player_name::*;
 pub(crate) use plugin::*;
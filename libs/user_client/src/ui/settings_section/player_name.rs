@@ -0,0 +1,148 @@
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::prelude::*;
+use bevy_cobweb::prelude::*;
+use bevy_cobweb_ui::prelude::*;
+use game_core::{validate_player_name, InvalidPlayerName, MAX_PLAYER_NAME_CHARS};
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Default)]
+struct RefreshPlayerName;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Edits the player name being typed. Press Enter to save the name and Escape to cancel.
+///
+/// Saving an empty name clears the player name.
+fn edit_name_draft(
+    mut keys: EventReader<KeyboardInput>,
+    mut draft: ResMut<NameDraft>,
+    mut player_name: ResMut<LocalPlayerName>,
+    section: Res<MenuContentSection>,
+)
+{
+    if !draft.editing {
+        keys.clear();
+        return;
+    }
+    if *section != MenuContentSection::Settings {
+        *draft = NameDraft::default();
+        return;
+    }
+
+    for key in keys.read() {
+        if !key.state.is_pressed() {
+            continue;
+        }
+
+        match &key.logical_key {
+            Key::Enter => {
+                let name = draft.text.trim().to_string();
+                if name.is_empty() {
+                    player_name.0 = None;
+                    *draft = NameDraft::default();
+                    return;
+                }
+                if let Err(err) = validate_player_name(&name) {
+                    draft.error = Some(err);
+                    continue;
+                }
+
+                tracing::debug!(?name, "changed player name");
+                player_name.0 = Some(name);
+                *draft = NameDraft::default();
+                return;
+            }
+            Key::Escape => {
+                *draft = NameDraft::default();
+                return;
+            }
+            Key::Backspace => {
+                draft.text.pop();
+            }
+            Key::Space => draft.text.push(' '),
+            Key::Character(chars) => draft.text.push_str(chars),
+            _ => (),
+        }
+
+        // don't let the draft grow far past the name limit
+        if draft.text.chars().count() > MAX_PLAYER_NAME_CHARS * 2 {
+            draft.text.pop();
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Sets up the player name editor.
+pub(super) fn build_player_name(h: &mut UiSceneHandle)
+{
+    h.get("name::text").update_on(
+        broadcast::<RefreshPlayerName>(),
+        |id: TargetId, mut e: TextEditor, draft: Res<NameDraft>, player_name: Res<LocalPlayerName>| {
+            if draft.editing {
+                write_text!(e, *id, "> {}_", draft.text);
+                return;
+            }
+
+            match &player_name.0 {
+                Some(name) => write_text!(e, *id, "{}", name),
+                None => write_text!(e, *id, "(default)"),
+            };
+        },
+    );
+    h.get("name::edit_button")
+        .on_pressed(|mut draft: ResMut<NameDraft>, player_name: Res<LocalPlayerName>| {
+            *draft = NameDraft {
+                editing: true,
+                text: player_name.0.clone().unwrap_or_default(),
+                error: None,
+            };
+        });
+    h.get("name_error").update_on(
+        broadcast::<RefreshPlayerName>(),
+        |id: TargetId, mut e: TextEditor, draft: Res<NameDraft>| {
+            match (draft.editing, draft.error) {
+                (true, Some(err)) => write_text!(e, *id, "Name {}.", err),
+                (true, None) => write_text!(e, *id, "Press Enter to save, or Esc to cancel."),
+                (false, _) => write_text!(e, *id, ""),
+            };
+        },
+    );
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Player name being typed in the settings section.
+#[derive(Resource, Default, Debug)]
+struct NameDraft
+{
+    editing: bool,
+    text: String,
+    /// Why the last submitted name was rejected.
+    error: Option<InvalidPlayerName>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+pub(super) struct UiPlayerNamePlugin;
+
+impl Plugin for UiPlayerNamePlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        app.init_resource::<NameDraft>().add_systems(
+            Update,
+            (
+                edit_name_draft,
+                broadcast_system::<RefreshPlayerName>
+                    .run_if(resource_changed::<NameDraft>.or(resource_changed::<LocalPlayerName>)),
+            )
+                .chain(),
+        );
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -1,9 +1,15 @@
 use bevy::prelude::*;
 use bevy_cobweb_ui::prelude::*;
 
+use super::*;
+
 //-------------------------------------------------------------------------------------------------------------------
 
-pub(crate) fn build_settings_section(_h: &mut UiSceneHandle) {}
+pub(crate) fn build_settings_section(h: &mut UiSceneHandle)
+{
+    build_player_name(h);
+    build_input_bindings(h);
+}
 
 //-------------------------------------------------------------------------------------------------------------------
 
@@ -11,7 +17,11 @@ pub(crate) struct UiSettingsSectionPlugin;
 
 impl Plugin for UiSettingsSectionPlugin
 {
-    fn build(&self, _app: &mut App) {}
+    fn build(&self, app: &mut App)
+    {
+        app.add_plugins(UiPlayerNamePlugin)
+            .add_plugins(UiInputBindingsPlugin);
+    }
 }
 
 //-------------------------------------------------------------------------------------------------------------------
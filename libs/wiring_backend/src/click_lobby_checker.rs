@@ -1,5 +1,6 @@
 use bevy_girk_backend_public::*;
 use bevy_girk_utils::*;
+use game_core::{validate_player_name, PlayerHandicap};
 
 use crate::*;
 
@@ -14,6 +15,8 @@ pub struct ClickLobbyChecker
     pub max_lobby_watchers: u16,
     /// Min number of players in a lobby required to launch a lobby.
     pub min_players_to_launch: u16,
+    /// Live lobby state managed by the lobby control server.
+    pub control: LobbyControl,
 }
 
 impl ClickLobbyChecker
@@ -40,6 +43,18 @@ impl ClickLobbyChecker
         }
         true
     }
+
+    /// Check if a handicap is within acceptable bounds. Handicaps can't stop players from scoring.
+    pub fn valid_handicap(handicap: &PlayerHandicap) -> bool
+    {
+        if handicap.points_per_click == 0 || handicap.points_per_click > MAX_POINTS_PER_CLICK {
+            return false;
+        }
+        if handicap.click_cooldown_ticks > MAX_CLICK_COOLDOWN_TICKS {
+            return false;
+        }
+        true
+    }
 }
 
 impl LobbyChecker for ClickLobbyChecker
@@ -65,6 +80,60 @@ impl LobbyChecker for ClickLobbyChecker
             return false;
         }
 
+        // team games need at least two teams, and every team must be able to have a player
+        if config.num_teams == 1 {
+            return false;
+        }
+        if config.num_teams as u16 > config.max_players {
+            return false;
+        }
+
+        // handicaps must be within acceptable bounds, and there can't be more handicaps than players
+        if config.handicaps.len() > config.max_players as usize {
+            return false;
+        }
+        if !config.handicaps.values().all(Self::valid_handicap) {
+            return false;
+        }
+
+        // game settings must be within acceptable bounds
+        if config.prep_secs > MAX_PREP_SECS {
+            return false;
+        }
+        if config.game_secs < MIN_GAME_SECS || config.game_secs > MAX_GAME_SECS {
+            return false;
+        }
+        let valid_mode = match config.game_mode {
+            ClickLobbyGameMode::Timed => true,
+            ClickLobbyGameMode::FirstTo { target_clicks } => (1..=MAX_TARGET_CLICKS).contains(&target_clicks),
+            // there must be time for at least two rounds
+            ClickLobbyGameMode::Elimination { round_secs } => {
+                round_secs >= MIN_ROUND_SECS && round_secs as u32 * 2 <= config.game_secs as u32
+            }
+            ClickLobbyGameMode::Targets { spawn_interval_ms, lifetime_ms } => {
+                (MIN_TARGET_MILLIS..=MAX_TARGET_MILLIS).contains(&spawn_interval_ms)
+                    && (MIN_TARGET_MILLIS..=MAX_TARGET_MILLIS).contains(&lifetime_ms)
+            }
+        };
+        if !valid_mode {
+            return false;
+        }
+
+        // owner names must be valid player names
+        if let Some(name) = &config.owner_name {
+            if validate_player_name(name).is_err() {
+                return false;
+            }
+        }
+
+        // ban lists are capped to keep lobby data small, and owners can't ban themselves
+        if config.banned_users.len() > MAX_LOBBY_BANS {
+            return false;
+        }
+        if config.banned_users.contains(&lobby.data.owner_id) {
+            return false;
+        }
+
         // get max count member types
         let Ok((num_players, num_watchers)) = Self::count_members(&lobby.data) else {
             return false;
@@ -115,6 +184,16 @@ impl LobbyChecker for ClickLobbyChecker
             return false;
         };
 
+        // check if the lobby owner banned the member
+        // - bans can be changed after the lobby is made, so the config only has the initial bans
+        let banned = self
+            .control
+            .is_banned(lobby.data.id, member_id)
+            .unwrap_or_else(|| config.banned_users.contains(&member_id));
+        if banned {
+            return false;
+        }
+
         match member_type {
             ClickLobbyMemberType::Player => {
                 if num_players >= config.max_players as usize {
@@ -139,6 +218,36 @@ impl LobbyChecker for ClickLobbyChecker
             return false;
         };
 
+        // kicked and banned members may not have left yet
+        let lobby_id = lobby.data.id;
+        let is_removed = |member_id: u128| {
+            self.control.is_kicked(lobby_id, member_id)
+                || self.control.is_banned(lobby_id, member_id) == Some(true)
+        };
+        if lobby
+            .data
+            .members
+            .iter()
+            .any(|(member_id, _)| is_removed(*member_id))
+        {
+            return false;
+        }
+
+        // all current players must be ready
+        // - ready states are looked up for the lobby's members, so members who left can't block the launch
+        let is_player = |member_data: &LobbyMemberData| {
+            ClickLobbyMemberType::try_from(member_data.color) == Ok(ClickLobbyMemberType::Player)
+        };
+        let all_ready = lobby
+            .data
+            .members
+            .iter()
+            .filter(|(_, member_data)| is_player(member_data))
+            .all(|(member_id, _)| self.control.is_ready(lobby_id, *member_id));
+        if !all_ready {
+            return false;
+        }
+
         Self::can_launch_hosted(num_players, self.min_players_to_launch as usize)
     }
 }
@@ -1,5 +1,8 @@
+use std::collections::{HashMap, HashSet};
+
 use bevy_girk_backend_public::*;
 use bevy_girk_utils::*;
+use game_core::{BotDifficulty, GameMode, PlayerHandicap};
 use renet2_setup::ConnectionType;
 use serde::{Deserialize, Serialize};
 
@@ -7,6 +10,92 @@ use crate::*;
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Converts a duration in milliseconds to game ticks, rounding down.
+fn millis_to_ticks(millis: u32, ticks_per_sec: u32) -> u32
+{
+    ((millis as u64 * ticks_per_sec as u64) / 1000) as u32
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Game mode chosen by a lobby owner.
+///
+/// Durations are in real time, and are converted to ticks by [`get_launch_pack`] when the game is launched.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub enum ClickLobbyGameMode
+{
+    /// See [`GameMode::Timed`].
+    #[default]
+    Timed,
+    /// See [`GameMode::FirstTo`].
+    FirstTo
+    {
+        target_clicks: u32,
+    },
+    /// See [`GameMode::Elimination`].
+    Elimination
+    {
+        round_secs: u16,
+    },
+    /// See [`GameMode::Targets`].
+    Targets
+    {
+        spawn_interval_ms: u32,
+        lifetime_ms: u32,
+    },
+}
+
+impl ClickLobbyGameMode
+{
+    /// All modes with their default settings, in display order.
+    pub const ALL: [ClickLobbyGameMode; 4] = [
+        Self::Timed,
+        Self::FirstTo { target_clicks: 100 },
+        Self::Elimination { round_secs: 10 },
+        Self::Targets { spawn_interval_ms: 1000, lifetime_ms: 2000 },
+    ];
+
+    /// Gets the display name of the mode.
+    pub fn name(&self) -> &'static str
+    {
+        match self {
+            Self::Timed => "Timed",
+            Self::FirstTo { .. } => "First To",
+            Self::Elimination { .. } => "Elimination",
+            Self::Targets { .. } => "Targets",
+        }
+    }
+
+    /// Gets the index of the mode in [`Self::ALL`].
+    pub fn index(&self) -> usize
+    {
+        match self {
+            Self::Timed => 0,
+            Self::FirstTo { .. } => 1,
+            Self::Elimination { .. } => 2,
+            Self::Targets { .. } => 3,
+        }
+    }
+
+    /// Converts the mode to a [`GameMode`] for a game running at `ticks_per_sec`.
+    pub fn to_game_mode(&self, ticks_per_sec: u32) -> GameMode
+    {
+        match *self {
+            Self::Timed => GameMode::Timed,
+            Self::FirstTo { target_clicks } => GameMode::FirstTo { target_clicks },
+            Self::Elimination { round_secs } => {
+                GameMode::Elimination { round_ticks: round_secs as u32 * ticks_per_sec }
+            }
+            Self::Targets { spawn_interval_ms, lifetime_ms } => GameMode::Targets {
+                spawn_interval_ticks: millis_to_ticks(spawn_interval_ms, ticks_per_sec).max(1),
+                lifetime_ticks: millis_to_ticks(lifetime_ms, ticks_per_sec).max(1),
+            },
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ClickLobbyConfig
 {
@@ -14,10 +103,38 @@ pub struct ClickLobbyConfig
     pub max_players: u16,
     /// Max watchers allowed in the lobby.
     pub max_watchers: u16,
+    /// Number of teams players are split into. Teams are disabled if this is `0`.
+    pub num_teams: u8,
+    /// If set, empty player slots are filled with bots of this difficulty when the game starts.
+    pub bot_difficulty: Option<BotDifficulty>,
+    /// If set, players can only see their own score until the game ends.
+    pub hidden_scores: bool,
+    /// Handicaps for balancing mixed-skill games, keyed by user id. Players without an entry have no handicap.
+    pub handicaps: HashMap<u128, PlayerHandicap>,
+    /// Length of [`GameState::Prep`](game_core::GameState::Prep) in seconds.
+    pub prep_secs: u16,
+    /// Length of [`GameState::Play`](game_core::GameState::Play) in seconds.
+    pub game_secs: u16,
+    /// Game mode.
+    pub game_mode: ClickLobbyGameMode,
+    /// Display name of the lobby owner.
+    ///
+    /// Must pass [`validate_player_name`](game_core::validate_player_name) if set.
+    pub owner_name: Option<String>,
+    /// Users that may not join the lobby.
+    ///
+    /// These are the initial bans. The owner can change bans and kick members while the lobby is open through
+    /// the lobby control server (see [`LobbyControl`]).
+    pub banned_users: HashSet<u128>,
 }
 
 impl ClickLobbyConfig
 {
+    pub fn has_teams(&self) -> bool
+    {
+        self.num_teams > 0
+    }
+
     pub fn is_single_player(&self) -> bool
     {
         self.max_players == 1 && self.max_watchers == 0
@@ -106,6 +223,15 @@ impl ClickLobbyContents
         }
     }
 
+    /// Gets the owner's display name, or an abbreviated owner id if the owner hasn't chosen a name.
+    pub fn owner_label(&self) -> String
+    {
+        match &self.config.owner_name {
+            Some(name) => name.clone(),
+            None => format!("{:0>6}", self.owner_id % 1_000_000u128),
+        }
+    }
+
     /// Check if the game can be launched while hosted by a server.
     ///
     /// This can be used to indicate to a user if a lobby is ready to launch.
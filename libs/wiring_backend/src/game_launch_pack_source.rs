@@ -1,7 +1,8 @@
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
 
 use bevy_girk_backend_public::*;
 use bevy_girk_game_instance::*;
+use game_core::{default_player_name, BotDifficulty, GameDurationConfig, PlayerHandicap};
 #[cfg(not(target_family = "wasm"))]
 use rand::seq::SliceRandom;
 #[cfg(not(target_family = "wasm"))]
@@ -28,15 +29,32 @@ fn get_protocol_id() -> u64
 
 //-------------------------------------------------------------------------------------------------------------------
 
-fn make_player_init_data(connection: ConnectionType, user_id: u128, client_id: ClientId) -> ClientGameInit
+fn make_player_init_data(
+    connection: ConnectionType,
+    user_id: u128,
+    client_id: ClientId,
+    player_name: String,
+    team: Option<u8>,
+    handicap: PlayerHandicap,
+) -> ClientGameInit
 {
-    let client_type = ClientTypeInfo::Player { player_name: format!("player{}", client_id) };
+    let client_type = ClientTypeInfo::Player { player_name, team, handicap };
 
     ClientGameInit { connection, user_id, client_id, client_type }
 }
 
 //-------------------------------------------------------------------------------------------------------------------
 
+fn make_bot_init_data(client_id: ClientId, difficulty: BotDifficulty, team: Option<u8>) -> ClientGameInit
+{
+    // bots don't connect to the game, so the connection type and user id are placeholders
+    let client_type = ClientTypeInfo::Bot { difficulty, team };
+
+    ClientGameInit { connection: ConnectionType::Memory, user_id: 0u128, client_id, client_type }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 fn make_watcher_init_data(connection: ConnectionType, user_id: u128, client_id: ClientId) -> ClientGameInit
 {
     let client_type = ClientTypeInfo::Watcher;
@@ -48,33 +66,144 @@ fn make_watcher_init_data(connection: ConnectionType, user_id: u128, client_id:
 
 fn launch_pack_from_req(
     game_factory_config: &ClickGameFactoryConfig,
+    ratings: &PlayerRatingsStore,
     start_request: &GameStartRequest,
+    member_states: Option<LobbyMemberStates>,
 ) -> Result<GameLaunchPack, ()>
 {
     // extract players/watchers from lobby data
-    let Ok(lobby_contents) = ClickLobbyContents::try_from(start_request.lobby_data.clone()) else {
+    let Ok(mut lobby_contents) = ClickLobbyContents::try_from(start_request.lobby_data.clone()) else {
         tracing::error!("unable to extract lobby contents from lobby data");
         return Err(());
     };
 
-    get_launch_pack(game_factory_config.clone(), lobby_contents)
+    // apply changes made after the lobby was created
+    let names = match member_states {
+        Some(states) => {
+            lobby_contents.config.handicaps = states.handicaps;
+            states.names
+        }
+        None => owner_names(&lobby_contents),
+    };
+
+    let game_id = lobby_contents.id;
+    let data = make_launch_data(game_factory_config.clone(), lobby_contents, names, Some(ratings))?;
+
+    // register the players so the game can be rated when it ends
+    let user_ids: HashMap<ClientId, u128> = data
+        .clients
+        .iter()
+        .filter(|client| matches!(client.client_type, ClientTypeInfo::Player { .. }))
+        .map(|client| (client.client_id, client.user_id))
+        .collect();
+    ratings.register_game(game_id, user_ids);
+
+    Ok(GameLaunchPack::new(game_id, data))
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Gets the player names of a lobby that isn't tracked by [`LobbyControl`], which only includes the owner's name.
+fn owner_names(lobby_contents: &ClickLobbyContents) -> HashMap<u128, String>
+{
+    lobby_contents
+        .config
+        .owner_name
+        .iter()
+        .map(|name| (lobby_contents.owner_id, name.clone()))
+        .collect()
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Assigns players to teams so team sizes differ by at most one.
+///
+/// Teams pick players in snake order (e.g. `0, 1, 1, 0, 0, 1`), so if players are sorted from strongest to
+/// weakest then team strengths will be roughly even. Returns `None` for every player if there are no teams.
+///
+/// Note: This is only `pub` for testing purposes.
+pub fn balance_teams(num_players: usize, num_teams: u8) -> Vec<Option<u8>>
+{
+    if num_teams == 0 {
+        return vec![None; num_players];
+    }
+
+    let num_teams = num_teams as usize;
+    (0..num_players)
+        .map(|idx| {
+            let pick = idx % num_teams;
+            match (idx / num_teams) % 2 {
+                0 => Some(pick as u8),
+                _ => Some((num_teams - 1 - pick) as u8),
+            }
+        })
+        .collect()
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Sorts players from highest to lowest rating.
+fn sort_players_by_rating(players: &mut [(ConnectionType, u128)], ratings: &PlayerRatingsStore)
+{
+    // stable sort so players with equal ratings stay shuffled
+    players.sort_by(|(_, a), (_, b)| ratings.get(*b).rating.total_cmp(&ratings.get(*a).rating));
 }
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Makes a launch pack for a lobby.
+///
+/// The lobby's game settings override the duration config and game mode in the game factory config. Games made
+/// with this are unrated.
 pub fn get_launch_pack(
     game_factory_config: ClickGameFactoryConfig,
-    #[allow(unused_mut)] mut lobby_contents: ClickLobbyContents,
+    lobby_contents: ClickLobbyContents,
 ) -> Result<GameLaunchPack, ()>
 {
+    let game_id = lobby_contents.id;
+    let names = owner_names(&lobby_contents);
+    let data = make_launch_data(game_factory_config, lobby_contents, names, None)?;
+    Ok(GameLaunchPack::new(game_id, data))
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Makes the launch data for a lobby.
+///
+/// Players are named from `names` (keyed by user id), and players without a name get a default name. Teams are
+/// balanced by player rating if `ratings` is set.
+fn make_launch_data(
+    mut game_factory_config: ClickGameFactoryConfig,
+    mut lobby_contents: ClickLobbyContents,
+    names: HashMap<u128, String>,
+    ratings: Option<&PlayerRatingsStore>,
+) -> Result<LaunchData, ()>
+{
+    // apply the lobby's game settings
+    let config = &lobby_contents.config;
+    let ticks_per_sec = game_factory_config.game_fw_config.ticks_per_sec();
+    let overtime_ticks = game_factory_config.duration_config.overtime_ticks();
+    let prep_ticks = config.prep_secs as u32 * ticks_per_sec;
+    let game_ticks = config.game_secs as u32 * ticks_per_sec;
+    game_factory_config.duration_config =
+        GameDurationConfig::new(prep_ticks, game_ticks).with_overtime(overtime_ticks);
+    game_factory_config.game_mode = config.game_mode.to_game_mode(ticks_per_sec);
+
     // extract players/watchers from lobby contents
-    let num_players = lobby_contents.players.len();
+    let num_humans = lobby_contents.players.len();
     let num_watchers = lobby_contents.watchers.len();
 
+    // fill empty player slots with bots
+    let num_bots = match lobby_contents.config.bot_difficulty {
+        Some(_) => (lobby_contents.config.max_players as usize).saturating_sub(num_humans),
+        None => 0,
+    };
+    let num_players = num_humans + num_bots;
+
     // shuffle the game participants
     #[cfg(target_family = "wasm")]
     {
-        if num_players != 1 {
+        if num_humans != 1 {
             panic!("only single-player game instances are allowed on WASM");
         }
         if num_watchers != 0 {
@@ -90,9 +219,44 @@ pub fn get_launch_pack(
     // make init data for the clients
     let mut client_init_data = Vec::with_capacity(num_players + num_watchers);
 
+    // assign teams
+    // - players are sorted by rating first so teams are balanced
+    if let Some(ratings) = ratings {
+        if lobby_contents.config.num_teams > 0 {
+            sort_players_by_rating(&mut lobby_contents.players, ratings);
+        }
+    }
+    let teams = balance_teams(num_players, lobby_contents.config.num_teams);
+
     for (idx, (connection, player_user_id)) in lobby_contents.players.iter().enumerate() {
         let client_id = idx as u64;
-        client_init_data.push(make_player_init_data(*connection, *player_user_id, client_id));
+        let team = teams.get(idx).copied().flatten();
+        let handicap = lobby_contents
+            .config
+            .handicaps
+            .get(player_user_id)
+            .copied()
+            .unwrap_or_default();
+        let player_name = names
+            .get(player_user_id)
+            .cloned()
+            .unwrap_or_else(|| default_player_name(client_id));
+        client_init_data.push(make_player_init_data(
+            *connection,
+            *player_user_id,
+            client_id,
+            player_name,
+            team,
+            handicap,
+        ));
+    }
+
+    if let Some(difficulty) = lobby_contents.config.bot_difficulty {
+        for idx in num_humans..num_players {
+            let client_id = idx as u64;
+            let team = teams.get(idx).copied().flatten();
+            client_init_data.push(make_bot_init_data(client_id, difficulty, team));
+        }
     }
 
     for (idx, (connection, watcher_user_id)) in lobby_contents.watchers.iter().enumerate() {
@@ -100,9 +264,19 @@ pub fn get_launch_pack(
         client_init_data.push(make_watcher_init_data(*connection, *watcher_user_id, client_id));
     }
 
-    // launch pack
-    let data = LaunchData { config: game_factory_config, clients: client_init_data };
-    Ok(GameLaunchPack::new(lobby_contents.id, data))
+    // find the lobby owner's in-game id
+    let owner = lobby_contents
+        .players
+        .iter()
+        .position(|(_, user_id)| *user_id == lobby_contents.owner_id)
+        .map(|idx| idx as ClientId);
+
+    Ok(LaunchData {
+        config: game_factory_config,
+        clients: client_init_data,
+        owner,
+        hidden_scores: lobby_contents.config.hidden_scores,
+    })
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -111,6 +285,10 @@ pub struct ClickGameLaunchPackSource
 {
     /// Serialized config needed by game factory to start a game.
     game_factory_config: ClickGameFactoryConfig,
+    /// Live lobby state, which is discarded when a lobby launches.
+    control: LobbyControl,
+    /// Player ratings, for balancing teams and rating games.
+    ratings: PlayerRatingsStore,
 
     /// Queue of reports.
     queue: VecDeque<GameLaunchPackReport>,
@@ -118,9 +296,13 @@ pub struct ClickGameLaunchPackSource
 
 impl ClickGameLaunchPackSource
 {
-    pub fn new(game_factory_config: ClickGameFactoryConfig) -> ClickGameLaunchPackSource
+    pub fn new(
+        game_factory_config: ClickGameFactoryConfig,
+        control: LobbyControl,
+        ratings: PlayerRatingsStore,
+    ) -> ClickGameLaunchPackSource
     {
-        ClickGameLaunchPackSource { game_factory_config, queue: VecDeque::default() }
+        ClickGameLaunchPackSource { game_factory_config, control, ratings, queue: VecDeque::default() }
     }
 }
 
@@ -129,7 +311,9 @@ impl GameLaunchPackSourceImpl for ClickGameLaunchPackSource
     /// Request a launch pack for a specified game.
     fn request_launch_pack(&mut self, start_request: &GameStartRequest)
     {
-        match launch_pack_from_req(&self.game_factory_config, start_request) {
+        let member_states = self.control.remove_lobby(start_request.lobby_data.id);
+
+        match launch_pack_from_req(&self.game_factory_config, &self.ratings, start_request, member_states) {
             Ok(launch_pack) => self
                 .queue
                 .push_back(GameLaunchPackReport::Pack(launch_pack)),
@@ -8,5 +8,19 @@ pub const ACK_TIMEOUT_MILLIS: u64 = 14_000;
 pub const MAX_LOBBY_PLAYERS: u16 = 4;
 pub const MAX_LOBBY_WATCHERS: u16 = 4;
 pub const MIN_PLAYERS_TO_LAUNCH: u16 = 1;
+pub const MAX_LOBBY_BANS: usize = 100;
+pub const MAX_RATINGS_PER_REQUEST: usize = 16;
+
+pub const DEFAULT_PREP_SECS: u16 = 3;
+pub const MAX_PREP_SECS: u16 = 10;
+pub const DEFAULT_GAME_SECS: u16 = 30;
+pub const MIN_GAME_SECS: u16 = 10;
+pub const MAX_GAME_SECS: u16 = 300;
+pub const MAX_TARGET_CLICKS: u32 = 1_000;
+pub const MIN_ROUND_SECS: u16 = 5;
+pub const MIN_TARGET_MILLIS: u32 = 250;
+pub const MAX_TARGET_MILLIS: u32 = 10_000;
+pub const MAX_POINTS_PER_CLICK: u32 = 5;
+pub const MAX_CLICK_COOLDOWN_TICKS: u32 = 20;
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -2,8 +2,14 @@ mod click_lobby_checker;
 mod click_lobby_contents;
 mod game_launch_pack_source;
 mod host_client_config;
+mod lobby_control;
+mod player_ratings;
+mod player_ratings_store;
 
 pub use click_lobby_checker::*;
 pub use click_lobby_contents::*;
 pub use game_launch_pack_source::*;
 pub use host_client_config::*;
+pub use lobby_control::*;
+pub use player_ratings::*;
+pub use player_ratings_store::*;
@@ -0,0 +1,453 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use bevy::prelude::Resource;
+use bevy_girk_backend_public::Lobby;
+use bevy_girk_utils::deser_msg;
+use game_core::PlayerHandicap;
+use serde::{Deserialize, Serialize};
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Protocol version of the lobby control channel.
+const LOBBY_CONTROL_PROTOCOL_VERSION: &str = "click_lobby_control_v1";
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Live state of a hosted lobby's members.
+///
+/// The host server can't change a lobby after it is made, so member state is managed by the lobby control server
+/// and sent to lobby members whenever it changes.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct LobbyMemberStates
+{
+    /// Users that may not be in the lobby, including users banned in the lobby config.
+    pub banned: HashSet<u128>,
+    /// Members that are ready to play. The lobby can only launch when all players are ready.
+    pub ready: HashSet<u128>,
+    /// Player handicaps set by the lobby owner, including handicaps in the lobby config. Players without an entry
+    /// have no handicap.
+    pub handicaps: HashMap<u128, PlayerHandicap>,
+    /// Names chosen by members, including the owner name in the lobby config. Names are unique within a lobby.
+    pub names: HashMap<u128, String>,
+}
+
+impl LobbyMemberStates
+{
+    pub fn is_ready(&self, member_id: u128) -> bool
+    {
+        self.ready.contains(&member_id)
+    }
+
+    pub fn handicap(&self, member_id: u128) -> PlayerHandicap
+    {
+        self.handicaps.get(&member_id).copied().unwrap_or_default()
+    }
+
+    pub fn name(&self, member_id: u128) -> Option<&String>
+    {
+        self.names.get(&member_id)
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Messages sent by users to the lobby control server.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum UserToLobbyControlMsg
+{
+    /// Request the [`LobbyMemberStates`] of a lobby the user is in.
+    GetMemberStates
+    {
+        lobby_id: u64,
+    },
+    /// Remove a member from a lobby. Only the lobby owner can kick members.
+    Kick
+    {
+        lobby_id: u64,
+        member_id: u128,
+    },
+    /// Ban or unban a user from a lobby. Banned members are kicked. Only the lobby owner can ban users.
+    SetBanned
+    {
+        lobby_id: u64,
+        user_id: u128,
+        banned: bool,
+    },
+    /// Mark the user as ready or not ready to play in a lobby they are in.
+    SetReady
+    {
+        lobby_id: u64,
+        ready: bool,
+    },
+    /// Set a member's handicap. Only the lobby owner can set handicaps.
+    SetHandicap
+    {
+        lobby_id: u64,
+        member_id: u128,
+        handicap: PlayerHandicap,
+    },
+    /// Set the user's name in a lobby they are in, or clear it with `None`.
+    SetName
+    {
+        lobby_id: u64,
+        name: Option<String>,
+    },
+    /// Request the ratings of up to [`MAX_RATINGS_PER_REQUEST`] users.
+    GetRatings
+    {
+        user_ids: Vec<u128>,
+    },
+}
+
+impl UserToLobbyControlMsg
+{
+    /// The lobby the message is about, if any.
+    pub fn lobby_id(&self) -> Option<u64>
+    {
+        match self {
+            Self::GetMemberStates { lobby_id }
+            | Self::Kick { lobby_id, .. }
+            | Self::SetBanned { lobby_id, .. }
+            | Self::SetReady { lobby_id, .. }
+            | Self::SetHandicap { lobby_id, .. }
+            | Self::SetName { lobby_id, .. } => Some(*lobby_id),
+            Self::GetRatings { .. } => None,
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Messages sent by the lobby control server to users.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum LobbyControlToUserMsg
+{
+    /// Member states of a lobby the user is in. Sent on request and whenever the states change.
+    MemberStates
+    {
+        lobby_id: u64,
+        states: LobbyMemberStates,
+    },
+    /// The lobby owner removed the user from a lobby. The user should leave the lobby.
+    Kicked
+    {
+        lobby_id: u64,
+    },
+    /// Ratings of users. Sent on request, and to players when their rating changes.
+    Ratings
+    {
+        ratings: Vec<(u128, PlayerRating)>,
+    },
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Channel between users and the lobby control server.
+///
+/// Users connect with the same client id they use for the host server. The server also shares data owned by the
+/// backend that the host server can't send, such as player ratings.
+#[derive(Debug, Clone)]
+pub struct LobbyControlChannel;
+
+impl bevy_simplenet::ChannelPack for LobbyControlChannel
+{
+    type ConnectMsg = ();
+    type ServerMsg = LobbyControlToUserMsg;
+    type ServerResponse = ();
+    type ClientMsg = UserToLobbyControlMsg;
+    type ClientRequest = ();
+}
+
+#[cfg(feature = "server")]
+pub type LobbyControlServer = bevy_simplenet::Server<LobbyControlChannel>;
+#[cfg(feature = "server")]
+pub type LobbyControlServerEvent = bevy_simplenet::ServerEventFrom<LobbyControlChannel>;
+#[cfg(feature = "client")]
+pub type LobbyControlClient = bevy_simplenet::Client<LobbyControlChannel>;
+#[cfg(feature = "client")]
+pub type LobbyControlClientEvent = bevy_simplenet::ClientEventFrom<LobbyControlChannel>;
+
+#[cfg(feature = "server")]
+pub fn lobby_control_server_factory() -> bevy_simplenet::ServerFactory<LobbyControlChannel>
+{
+    bevy_simplenet::ServerFactory::<LobbyControlChannel>::new(LOBBY_CONTROL_PROTOCOL_VERSION)
+}
+
+#[cfg(feature = "client")]
+pub fn lobby_control_client_factory() -> bevy_simplenet::ClientFactory<LobbyControlChannel>
+{
+    bevy_simplenet::ClientFactory::<LobbyControlChannel>::new(LOBBY_CONTROL_PROTOCOL_VERSION)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Debug)]
+struct LobbyControlEntry
+{
+    owner_id: u128,
+    /// Members of the lobby other than the owner, as of the last sync with the host server.
+    members: HashSet<u128>,
+    /// Members that were kicked but haven't left the lobby yet.
+    kicked: HashSet<u128>,
+    states: LobbyMemberStates,
+}
+
+impl LobbyControlEntry
+{
+    /// Checks if a user is the owner or a member that wasn't kicked.
+    fn is_member(&self, user_id: u128) -> bool
+    {
+        self.owner_id == user_id || (self.members.contains(&user_id) && !self.kicked.contains(&user_id))
+    }
+
+    /// Forgets the ready state and name of a user who is no longer a member.
+    fn forget_member(&mut self, member_id: u128)
+    {
+        self.states.ready.remove(&member_id);
+        self.states.names.remove(&member_id);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Live lobby state shared by the [`ClickLobbyChecker`], the lobby control server, and the launch pack source.
+///
+/// Lobbies are added and their members are updated with [`LobbyControl::sync_lobby`], using the host server's
+/// copy of the lobby. Lobbies are removed when they launch or when the host server drops them.
+#[derive(Resource, Debug, Default, Clone)]
+pub struct LobbyControl
+{
+    lobbies: Arc<Mutex<HashMap<u64, LobbyControlEntry>>>,
+}
+
+impl LobbyControl
+{
+    fn lock(&self) -> MutexGuard<HashMap<u64, LobbyControlEntry>>
+    {
+        self.lobbies.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Syncs a lobby's members with the host server's copy of the lobby, adding the lobby if it isn't tracked yet.
+    ///
+    /// Members that left the lobby are forgotten, and users who join later start out not ready. Returns `true` if
+    /// the lobby's members changed.
+    pub fn sync_lobby(&self, lobby: &Lobby) -> bool
+    {
+        let lobby_id = lobby.data.id;
+        let owner_id = lobby.data.owner_id;
+        let mut lobbies = self.lock();
+
+        if !lobbies.contains_key(&lobby_id) {
+            let Some(config) = deser_msg::<ClickLobbyConfig>(&lobby.custom_data()) else { return false };
+            let states = LobbyMemberStates {
+                banned: config.banned_users.clone(),
+                handicaps: config.handicaps.clone(),
+                names: config
+                    .owner_name
+                    .iter()
+                    .map(|name| (owner_id, name.clone()))
+                    .collect(),
+                ..Default::default()
+            };
+            let entry = LobbyControlEntry {
+                owner_id,
+                members: HashSet::default(),
+                kicked: HashSet::default(),
+                states,
+            };
+            lobbies.insert(lobby_id, entry);
+        }
+        let Some(entry) = lobbies.get_mut(&lobby_id) else { return false };
+
+        let members: HashSet<u128> = lobby
+            .data
+            .members
+            .iter()
+            .map(|(member_id, _)| *member_id)
+            .filter(|member_id| *member_id != owner_id)
+            .collect();
+        if members == entry.members {
+            return false;
+        }
+
+        let departed: Vec<u128> = entry.members.difference(&members).copied().collect();
+        for member_id in departed {
+            entry.forget_member(member_id);
+        }
+        entry.kicked.retain(|member_id| members.contains(member_id));
+        entry.members = members;
+        true
+    }
+
+    /// Marks a member as kicked from a lobby.
+    ///
+    /// Kicked members can't change their member state, and the lobby can't launch until they leave. Returns
+    /// `false` if the user isn't a member of the lobby. The owner can't be kicked.
+    pub fn kick_member(&self, lobby_id: u64, member_id: u128) -> bool
+    {
+        let mut lobbies = self.lock();
+        let Some(entry) = lobbies.get_mut(&lobby_id) else { return false };
+        if member_id == entry.owner_id || !entry.is_member(member_id) {
+            return false;
+        }
+
+        entry.forget_member(member_id);
+        entry.kicked.insert(member_id);
+        true
+    }
+
+    /// Removes a lobby, returning its final member states.
+    pub fn remove_lobby(&self, lobby_id: u64) -> Option<LobbyMemberStates>
+    {
+        self.lock().remove(&lobby_id).map(|entry| entry.states)
+    }
+
+    /// Gets the ids of all tracked lobbies.
+    pub fn lobby_ids(&self) -> Vec<u64>
+    {
+        self.lock().keys().copied().collect()
+    }
+
+    pub fn owner(&self, lobby_id: u64) -> Option<u128>
+    {
+        self.lock().get(&lobby_id).map(|entry| entry.owner_id)
+    }
+
+    /// Checks if a user is the owner or a member of a lobby. Kicked members aren't counted.
+    pub fn has_member(&self, lobby_id: u64, user_id: u128) -> bool
+    {
+        self.lock()
+            .get(&lobby_id)
+            .map(|entry| entry.is_member(user_id))
+            .unwrap_or(false)
+    }
+
+    /// Gets the owner and members of a lobby. Kicked members aren't included.
+    pub fn members(&self, lobby_id: u64) -> Vec<u128>
+    {
+        let lobbies = self.lock();
+        let Some(entry) = lobbies.get(&lobby_id) else { return Vec::default() };
+        std::iter::once(entry.owner_id)
+            .chain(entry.members.difference(&entry.kicked).copied())
+            .collect()
+    }
+
+    pub fn member_states(&self, lobby_id: u64) -> Option<LobbyMemberStates>
+    {
+        self.lock().get(&lobby_id).map(|entry| entry.states.clone())
+    }
+
+    /// Checks if a user is banned from a lobby. Returns `None` if the lobby isn't tracked.
+    pub fn is_banned(&self, lobby_id: u64, user_id: u128) -> Option<bool>
+    {
+        self.lock()
+            .get(&lobby_id)
+            .map(|entry| entry.states.banned.contains(&user_id))
+    }
+
+    /// Bans or unbans a user from a lobby.
+    ///
+    /// Returns `false` if the lobby is unknown, or if the ban list is full. The owner can't be banned.
+    pub fn set_banned(&self, lobby_id: u64, user_id: u128, banned: bool) -> bool
+    {
+        let mut lobbies = self.lock();
+        let Some(entry) = lobbies.get_mut(&lobby_id) else { return false };
+
+        if !banned {
+            entry.states.banned.remove(&user_id);
+            return true;
+        }
+        if user_id == entry.owner_id || entry.states.banned.len() >= MAX_LOBBY_BANS {
+            return false;
+        }
+        entry.states.banned.insert(user_id);
+        true
+    }
+
+    /// Checks if a member was kicked from a lobby but hasn't left yet.
+    pub fn is_kicked(&self, lobby_id: u64, member_id: u128) -> bool
+    {
+        self.lock()
+            .get(&lobby_id)
+            .map(|entry| entry.kicked.contains(&member_id))
+            .unwrap_or(false)
+    }
+
+    /// Checks if a member is ready in a lobby.
+    pub fn is_ready(&self, lobby_id: u64, member_id: u128) -> bool
+    {
+        self.lock()
+            .get(&lobby_id)
+            .map(|entry| entry.states.is_ready(member_id))
+            .unwrap_or(false)
+    }
+
+    /// Sets a member's handicap.
+    ///
+    /// Returns `false` if the user isn't the owner or a member of the lobby.
+    pub fn set_handicap(&self, lobby_id: u64, member_id: u128, handicap: PlayerHandicap) -> bool
+    {
+        let mut lobbies = self.lock();
+        let Some(entry) = lobbies.get_mut(&lobby_id) else { return false };
+        if !entry.is_member(member_id) {
+            return false;
+        }
+
+        match handicap.is_none() {
+            true => entry.states.handicaps.remove(&member_id),
+            false => entry.states.handicaps.insert(member_id, handicap),
+        };
+        true
+    }
+
+    /// Sets or clears a member's name.
+    ///
+    /// Returns `false` if the user isn't the owner or a member of the lobby, or if another member has the
+    /// same name (ignoring case).
+    pub fn set_name(&self, lobby_id: u64, member_id: u128, name: Option<String>) -> bool
+    {
+        let mut lobbies = self.lock();
+        let Some(entry) = lobbies.get_mut(&lobby_id) else { return false };
+        if !entry.is_member(member_id) {
+            return false;
+        }
+
+        let Some(name) = name else {
+            entry.states.names.remove(&member_id);
+            return true;
+        };
+        let taken = entry
+            .states
+            .names
+            .iter()
+            .any(|(other_id, other)| *other_id != member_id && other.to_lowercase() == name.to_lowercase());
+        if taken {
+            return false;
+        }
+        entry.states.names.insert(member_id, name);
+        true
+    }
+
+    /// Marks a member as ready or not ready.
+    ///
+    /// Returns `false` if the user isn't the owner or a member of the lobby.
+    pub fn set_ready(&self, lobby_id: u64, member_id: u128, ready: bool) -> bool
+    {
+        let mut lobbies = self.lock();
+        let Some(entry) = lobbies.get_mut(&lobby_id) else { return false };
+        if !entry.is_member(member_id) {
+            return false;
+        }
+
+        match ready {
+            true => entry.states.ready.insert(member_id),
+            false => entry.states.ready.remove(&member_id),
+        };
+        true
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,144 @@
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+
+use bevy_girk_utils::*;
+use game_core::ClickGameOverReport;
+use renet2::ClientId;
+use serde::{Deserialize, Serialize};
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Rating given to users who haven't finished a rated game.
+pub const DEFAULT_PLAYER_RATING: f32 = 1500.0;
+/// Max rating change from a single game.
+const RATING_K_FACTOR: f32 = 32.0;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Gets the expected score of a player against an opponent, between 0 and 1.
+fn expected_score(rating: f32, opponent_rating: f32) -> f32
+{
+    1.0 / (1.0 + 10f32.powf((opponent_rating - rating) / 400.0))
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A user's rating.
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlayerRating
+{
+    /// Elo rating.
+    pub rating: f32,
+    /// Number of rated games the user has finished.
+    pub games: u32,
+}
+
+impl Default for PlayerRating
+{
+    fn default() -> Self
+    {
+        Self { rating: DEFAULT_PLAYER_RATING, games: 0 }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Elo ratings of users, keyed by server-side user id.
+///
+/// Each game is rated as a set of head-to-head matches between every pair of rated players, using final ranks
+/// (or team ranks in team games). Teammates are not rated against each other. Bots, watchers, and players
+/// flagged for automated clicking are not rated.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct PlayerRatings
+{
+    users: HashMap<u128, PlayerRating>,
+}
+
+impl PlayerRatings
+{
+    /// Gets a user's rating.
+    pub fn get(&self, user_id: u128) -> PlayerRating
+    {
+        self.users.get(&user_id).copied().unwrap_or_default()
+    }
+
+    /// Updates ratings from the results of a game.
+    ///
+    /// Players not in `user_ids` are ignored.
+    pub fn apply_game(&mut self, report: &ClickGameOverReport, user_ids: &HashMap<ClientId, u128>)
+    {
+        let team_ranks: BTreeMap<u8, u32> = report
+            .team_reports
+            .iter()
+            .map(|team| (team.team, team.rank))
+            .collect();
+
+        // collect rated players
+        // - (user id, team, rank, rating before the game)
+        let rated: Vec<(u128, Option<u8>, u32, f32)> = report
+            .player_reports
+            .iter()
+            .filter(|player| !player.flagged)
+            .filter_map(|player| {
+                let user_id = *user_ids.get(&player.client_id)?;
+                let rank = player
+                    .team
+                    .and_then(|team| team_ranks.get(&team).copied())
+                    .unwrap_or(player.rank);
+                Some((user_id, player.team, rank, self.get(user_id).rating))
+            })
+            .collect();
+
+        for (user_id, team, rank, rating) in rated.iter().copied() {
+            let mut delta = 0.0;
+            let mut num_opponents = 0;
+            for (other_user_id, other_team, other_rank, other_rating) in rated.iter().copied() {
+                if other_user_id == user_id || (team.is_some() && other_team == team) {
+                    continue;
+                }
+                let score = match rank.cmp(&other_rank) {
+                    std::cmp::Ordering::Less => 1.0,
+                    std::cmp::Ordering::Equal => 0.5,
+                    std::cmp::Ordering::Greater => 0.0,
+                };
+                delta += score - expected_score(rating, other_rating);
+                num_opponents += 1;
+            }
+            if num_opponents == 0 {
+                continue;
+            }
+
+            let entry = self.users.entry(user_id).or_default();
+            entry.rating += RATING_K_FACTOR * delta / num_opponents as f32;
+            entry.games += 1;
+            tracing::debug!(user_id, rating = entry.rating, games = entry.games, "updated player rating");
+        }
+    }
+
+    /// Reads ratings from a file.
+    ///
+    /// Returns empty ratings if the file doesn't exist.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, String>
+    {
+        if !path.as_ref().exists() {
+            return Ok(Self::default());
+        }
+        let bytes = std::fs::read(path.as_ref())
+            .map_err(|err| format!("failed reading player ratings from {:?}: {err:?}", path.as_ref()))?;
+        deser_msg::<Self>(&bytes)
+            .ok_or_else(|| format!("failed deserializing player ratings from {:?}", path.as_ref()))
+    }
+
+    /// Writes ratings to a file.
+    ///
+    /// The ratings are written to a temporary file first so readers never see a partially-written file.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), String>
+    {
+        let temp_path = path.as_ref().with_extension("tmp");
+        std::fs::write(&temp_path, ser_msg(self))
+            .and_then(|()| std::fs::rename(&temp_path, path.as_ref()))
+            .map_err(|err| format!("failed writing player ratings to {:?}: {err:?}", path.as_ref()))
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
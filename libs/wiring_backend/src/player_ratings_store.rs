@@ -0,0 +1,102 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use bevy::prelude::Resource;
+use game_core::ClickGameOverReport;
+use renet2::ClientId;
+
+use crate::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Debug, Default)]
+struct PlayerRatingsStoreInner
+{
+    /// File where ratings are saved. Ratings are only kept in memory if `None`.
+    path: Option<PathBuf>,
+    ratings: PlayerRatings,
+    /// Server-side user ids of players in running games, keyed by game id then client id.
+    games: HashMap<u64, HashMap<ClientId, u128>>,
+    /// Users whose ratings changed since the last call to [`PlayerRatingsStore::take_updated`].
+    updated: Vec<u128>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Player ratings owned by the backend.
+///
+/// Games are registered by the launch pack source when they launch, and rated when the backend receives their
+/// game over report. Only the backend writes the ratings file, so concurrent games can't overwrite each other's
+/// updates.
+#[derive(Resource, Debug, Default, Clone)]
+pub struct PlayerRatingsStore
+{
+    inner: Arc<Mutex<PlayerRatingsStoreInner>>,
+}
+
+impl PlayerRatingsStore
+{
+    /// Makes a ratings store, loading existing ratings from `path` if it is set.
+    pub fn load(path: Option<PathBuf>) -> Result<Self, String>
+    {
+        let ratings = match &path {
+            Some(path) => PlayerRatings::load(path)?,
+            None => PlayerRatings::default(),
+        };
+        let inner = PlayerRatingsStoreInner { path, ratings, ..Default::default() };
+        Ok(Self { inner: Arc::new(Mutex::new(inner)) })
+    }
+
+    fn lock(&self) -> MutexGuard<PlayerRatingsStoreInner>
+    {
+        self.inner.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Gets a user's rating.
+    pub fn get(&self, user_id: u128) -> PlayerRating
+    {
+        self.lock().ratings.get(user_id)
+    }
+
+    /// Records the players of a game so the game can be rated when it ends.
+    pub fn register_game(&self, game_id: u64, user_ids: HashMap<ClientId, u128>)
+    {
+        self.lock().games.insert(game_id, user_ids);
+    }
+
+    /// Forgets a game without rating it.
+    pub fn remove_game(&self, game_id: u64)
+    {
+        self.lock().games.remove(&game_id);
+    }
+
+    /// Rates a registered game from its game over report, then saves the ratings.
+    ///
+    /// Games are only rated once.
+    pub fn apply_game_over(&self, game_id: u64, report: &ClickGameOverReport)
+    {
+        let mut inner = self.lock();
+        let Some(user_ids) = inner.games.remove(&game_id) else {
+            tracing::warn!(game_id, "ignoring game over report for unregistered game");
+            return;
+        };
+
+        inner.ratings.apply_game(report, &user_ids);
+        inner.updated.extend(user_ids.values().copied());
+
+        let Some(path) = &inner.path else { return };
+        match inner.ratings.save(path) {
+            Ok(()) => tracing::info!(game_id, ?path, "updated player ratings"),
+            Err(err) => tracing::error!("{err}"),
+        }
+    }
+
+    /// Takes the users whose ratings changed since the last call.
+    pub fn take_updated(&self) -> Vec<u128>
+    {
+        std::mem::take(&mut self.lock().updated)
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
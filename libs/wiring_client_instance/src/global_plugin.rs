@@ -175,6 +175,7 @@ fn setup(mut c: Commands)
 /// - `ClientInstancePlugin` plugin *with* game factory for local games
 /// - [`TimerConfigs`] resource
 /// - [`HostClientConstructor`] resource
+/// - [`LobbyControlClientConstructor`] resource
 pub struct ClickClientGlobalPlugin;
 
 impl Plugin for ClickClientGlobalPlugin
@@ -24,15 +24,21 @@ pub struct ClientContext
 
     /// The game duration config.
     duration_config: GameDurationConfig,
+    /// The game mode.
+    game_mode: GameMode,
 }
 
 impl ClientContext
 {
     /// New context
-    pub fn new(client_id: ClientId, client_type: ClientType, duration_config: GameDurationConfig)
-        -> ClientContext
+    pub fn new(
+        client_id: ClientId,
+        client_type: ClientType,
+        duration_config: GameDurationConfig,
+        game_mode: GameMode,
+    ) -> ClientContext
     {
-        ClientContext { client_id, client_type, duration_config }
+        ClientContext { client_id, client_type, duration_config, game_mode }
     }
 
     pub fn id(&self) -> ClientId
@@ -47,6 +53,10 @@ impl ClientContext
     {
         &self.duration_config
     }
+    pub fn game_mode(&self) -> GameMode
+    {
+        self.game_mode
+    }
 }
 
 //-------------------------------------------------------------------------------------------------------------------
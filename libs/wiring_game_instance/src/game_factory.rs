@@ -1,4 +1,5 @@
 use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
 use std::time::Duration;
 use std::vec::Vec;
 
@@ -30,12 +31,17 @@ fn prepare_game_startup(
     game_id: u64,
     config: &GameFwConfig,
     client_init_data: Vec<ClientGameInit>,
-    duration_config: GameDurationConfig,
+    rules: ClickGameRules,
+    owner: Option<ClientId>,
+    hidden_scores: bool,
 ) -> Result<GameStartupHelper, String>
 {
     // prepare each client
+    let duration_config = rules.duration_config;
+    let game_mode = rules.game_mode;
     let mut client_set = HashSet::with_capacity(client_init_data.len());
     let mut players = HashMap::with_capacity(client_init_data.len());
+    let mut bots = HashMap::default();
     let mut watchers = HashSet::with_capacity(client_init_data.len());
     let mut start_infos = Vec::with_capacity(client_init_data.len());
     let mut client_counts = ClientCounts::default();
@@ -45,25 +51,41 @@ fn prepare_game_startup(
 
         // handle client type
         let initializer = match client_init.client_type {
-            ClientTypeInfo::Player { player_name } => {
+            ClientTypeInfo::Player { player_name, team, handicap } => {
                 players.insert(
                     client_id,
                     PlayerState {
                         id: PlayerId { id: client_id },
                         name: PlayerName { name: player_name },
+                        team: team.map(|team| PlayerTeam { team }),
+                        handicap,
                         ..Default::default()
                     },
                 );
                 ClientInitializer {
-                    context: ClientContext::new(client_id, ClientType::Player, duration_config),
+                    context: ClientContext::new(client_id, ClientType::Player, duration_config, game_mode),
                 }
             }
             ClientTypeInfo::Watcher => {
                 watchers.insert(client_id);
                 ClientInitializer {
-                    context: ClientContext::new(client_id, ClientType::Watcher, duration_config),
+                    context: ClientContext::new(client_id, ClientType::Watcher, duration_config, game_mode),
                 }
             }
+            ClientTypeInfo::Bot { difficulty, team } => {
+                // bots are simulated by the game, so they don't get a network client
+                players.insert(
+                    client_id,
+                    PlayerState {
+                        id: PlayerId { id: client_id },
+                        name: PlayerName { name: format!("bot{}", client_id) },
+                        team: team.map(|team| PlayerTeam { team }),
+                        ..Default::default()
+                    },
+                );
+                bots.insert(client_id, difficulty);
+                continue;
+            }
         };
 
         // save client id for the game
@@ -94,11 +116,19 @@ fn prepare_game_startup(
         #[cfg(not(target_family = "wasm"))]
         bevy_girk_utils::gen_rand128()
     };
-    let game_context = ClickGameContext::new(seed, duration_config);
+    let game_context = ClickGameContext::new(seed, config.ticks_per_sec(), rules);
 
     Ok(GameStartupHelper {
         client_set: GameFwClients::new(client_set),
-        click_init: ClickGameInitializer { game_context, players, watchers },
+        click_init: ClickGameInitializer {
+            game_context,
+            players,
+            bots,
+            owner,
+            watchers,
+            viewers: HashSet::default(),
+            hidden_scores,
+        },
         start_infos,
         client_counts,
     })
@@ -113,9 +143,32 @@ pub struct ClickGameFactoryConfig
     pub server_setup_config: GameServerSetupConfig,
     pub game_fw_config: GameFwConfig,
     pub duration_config: GameDurationConfig,
+    pub game_mode: GameMode,
+    pub click_limit_config: ClickLimitConfig,
+    pub pause_config: PauseConfig,
+    pub chat_config: ChatConfig,
+    pub disconnect_config: DisconnectConfig,
+    /// Directory where replay files are written when games end. Replay files are not written if `None`.
+    pub replay_dir: Option<PathBuf>,
     pub resend_time: Duration,
 }
 
+impl ClickGameFactoryConfig
+{
+    /// Gets the rules of games made with this config.
+    pub fn rules(&self) -> ClickGameRules
+    {
+        ClickGameRules {
+            duration_config: self.duration_config,
+            game_mode: self.game_mode,
+            click_limit_config: self.click_limit_config,
+            pause_config: self.pause_config,
+            chat_config: self.chat_config,
+            disconnect_config: self.disconnect_config,
+        }
+    }
+}
+
 //-------------------------------------------------------------------------------------------------------------------
 
 /// Client init data used when setting up a game.
@@ -125,8 +178,19 @@ pub enum ClientTypeInfo
     Player
     {
         player_name: String,
+        /// Team index in team games.
+        team: Option<u8>,
+        /// Handicap for balancing mixed-skill games.
+        handicap: PlayerHandicap,
     },
     Watcher,
+    /// A player simulated by the game instance. Bots don't have a network client.
+    Bot
+    {
+        difficulty: BotDifficulty,
+        /// Team index in team games.
+        team: Option<u8>,
+    },
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -158,6 +222,12 @@ pub struct LaunchData
 
     /// Client init data for use in initializing a game.
     pub clients: Vec<ClientGameInit>,
+
+    /// In-game id of the lobby owner, if the owner is a player.
+    pub owner: Option<ClientId>,
+
+    /// If set, players can only see their own score until the game ends.
+    pub hidden_scores: bool,
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -185,7 +255,99 @@ impl GameFactoryImpl for ClickGameFactory
     {
         // initialize clients and game config
         let config = data.config;
-        let startup = prepare_game_startup(game_id, &config.game_fw_config, data.clients, config.duration_config)?;
+        let startup = prepare_game_startup(
+            game_id,
+            &config.game_fw_config,
+            data.clients,
+            config.rules(),
+            data.owner,
+            data.hidden_scores,
+        )?;
+
+        // girk server config
+        let server_config = GirkServerConfig {
+            clients: startup.client_set,
+            config: config.game_fw_config,
+            game_server_config: config.server_setup_config,
+            resend_time: config.resend_time,
+            client_counts: startup.client_counts,
+        };
+
+        // prepare game app
+        let metas = prepare_girk_game_app(app, server_config)?;
+        prepare_game_app_core(app, startup.click_init);
+        if let Some(replay_dir) = config.replay_dir {
+            app.insert_resource(ReplayOutput { path: replay_dir.join(format!("click_game_{game_id}.replay")) });
+        }
+
+        Ok(GameStartReport { metas, start_infos: startup.start_infos })
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Makes a launch pack for viewing a replay as a local game.
+///
+/// The only client is an in-memory watcher (the viewer). The recorded players are added to the game directly by
+/// [`ClickReplayGameFactory`].
+pub fn make_replay_launch_pack(replay: &ClickGameReplay, config: ClickGameFactoryConfig) -> GameLaunchPack
+{
+    // pick an id that doesn't collide with the recorded clients
+    let viewer_id = replay
+        .players
+        .iter()
+        .map(|player| player.client_id)
+        .chain(replay.watchers.iter().copied())
+        .max()
+        .map(|id| id + 1)
+        .unwrap_or_default();
+
+    let viewer = ClientGameInit {
+        connection: ConnectionType::Memory,
+        user_id: 0u128,
+        client_id: viewer_id,
+        client_type: ClientTypeInfo::Watcher,
+    };
+    let data = LaunchData { config, clients: vec![viewer], owner: None, hidden_scores: false };
+
+    GameLaunchPack::new(0u64, data)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Game factory for playing back a recorded game.
+///
+/// Use [`make_replay_launch_pack`] to make launch packs for this factory. The game framework config in the launch
+/// pack should tick [`REPLAY_TICK_MULTIPLIER`] times faster than the recorded game.
+#[derive(Debug)]
+pub struct ClickReplayGameFactory
+{
+    pub replay: ClickGameReplay,
+    pub control: ReplayControl,
+}
+
+impl GameFactoryImpl for ClickReplayGameFactory
+{
+    type Launch = LaunchData;
+
+    fn new_game(&self, app: &mut App, game_id: u64, data: LaunchData) -> Result<GameStartReport, String>
+    {
+        // initialize clients and game config
+        let config = data.config;
+        let mut startup = prepare_game_startup(
+            game_id,
+            &config.game_fw_config,
+            data.clients,
+            *self.replay.game_context.rules(),
+            self.replay.owner,
+            self.replay.hidden_scores,
+        )?;
+
+        // use the recorded context and roster
+        // - the viewers join as watchers, but are tracked separately so they don't change the recorded game
+        let viewers = std::mem::take(&mut startup.click_init.watchers);
+        startup.click_init = self.replay.initializer();
+        startup.click_init.viewers = viewers;
 
         // girk server config
         let server_config = GirkServerConfig {
@@ -199,6 +361,9 @@ impl GameFactoryImpl for ClickGameFactory
         // prepare game app
         let metas = prepare_girk_game_app(app, server_config)?;
         prepare_game_app_core(app, startup.click_init);
+        add_replay_inputs(app, self.replay.inputs.iter().cloned());
+        add_replay_connection_changes(app, self.replay.connection_changes.iter().copied());
+        app.add_plugins(ReplayPlaybackPlugin { control: self.control.clone() });
 
         Ok(GameStartReport { metas, start_infos: startup.start_infos })
     }
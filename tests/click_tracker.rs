@@ -0,0 +1,78 @@
+use game_core::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// 3 clicks per 20 ticks, and players are flagged if 4 sampled intervals have a variance below 0.5.
+fn make_config() -> ClickLimitConfig
+{
+    ClickLimitConfig::new(20, 3, 4, 0.5)
+}
+
+/// Records clicks at the given ticks.
+fn record_clicks(ticks: &[u32]) -> ClickTracker
+{
+    let config = make_config();
+    let mut tracker = ClickTracker::default();
+    for tick in ticks {
+        tracker.record(*tick, &config);
+    }
+    tracker
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn click_tracker_rate_limit()
+{
+    let config = make_config();
+    let mut tracker = record_clicks(&[0, 1, 2]);
+
+    assert!(tracker.is_rate_limited(3, &config));
+    // the click on tick 0 falls out of the window
+    assert!(!tracker.is_rate_limited(20, &config));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn click_tracker_flags_constant_intervals()
+{
+    let tracker = record_clicks(&[0, 5, 10, 15, 20]);
+    assert!(tracker.is_flagged());
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn click_tracker_allows_irregular_intervals()
+{
+    // intervals 3, 7, 2, 8 have a variance of 6.5
+    let tracker = record_clicks(&[0, 3, 10, 12, 20]);
+    assert!(!tracker.is_flagged());
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn click_tracker_needs_full_sample()
+{
+    // only 3 intervals
+    let tracker = record_clicks(&[0, 5, 10, 15]);
+    assert!(!tracker.is_flagged());
+
+    // clicks in the same tick aren't sampled, so this is also only 3 intervals
+    let tracker = record_clicks(&[0, 0, 5, 5, 10, 10, 15, 15]);
+    assert!(!tracker.is_flagged());
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn click_tracker_flag_is_sticky()
+{
+    // irregular clicks after being flagged don't clear the flag
+    let tracker = record_clicks(&[0, 5, 10, 15, 20, 23, 30, 32, 40]);
+    assert!(tracker.is_flagged());
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,30 @@
+use game_core::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn competition_ranks_unique()
+{
+    assert_eq!(competition_ranks(&[10u32, 30, 20]), vec![3, 1, 2]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn competition_ranks_ties_skip_ranks()
+{
+    assert_eq!(competition_ranks(&[5u32, 9, 5, 1]), vec![2, 1, 2, 4]);
+    assert_eq!(competition_ranks(&[3u32, 3, 3]), vec![1, 1, 1]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn competition_ranks_scores()
+{
+    let scores = [PlayerScore { score: 0 }, PlayerScore { score: 7 }, PlayerScore { score: 7 }];
+    assert_eq!(competition_ranks(&scores), vec![3, 1, 1]);
+    assert!(competition_ranks::<PlayerScore>(&[]).is_empty());
+}
+
+//-------------------------------------------------------------------------------------------------------------------
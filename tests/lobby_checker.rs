@@ -0,0 +1,143 @@
+use std::collections::{HashMap, HashSet};
+
+use bevy_girk_backend_public::*;
+use bevy_girk_utils::*;
+use renet2_setup::ConnectionType;
+use wiring_backend::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+const LOBBY_ID: u64 = 1;
+const OWNER: u128 = 10;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn make_checker() -> ClickLobbyChecker
+{
+    ClickLobbyChecker {
+        max_lobby_players: 4,
+        max_lobby_watchers: 2,
+        min_players_to_launch: 2,
+        control: LobbyControl::default(),
+    }
+}
+
+/// Makes a lobby owned by a player.
+fn make_lobby() -> Lobby
+{
+    let config = ClickLobbyConfig {
+        max_players: 4,
+        max_watchers: 2,
+        num_teams: 0,
+        bot_difficulty: None,
+        hidden_scores: false,
+        handicaps: HashMap::default(),
+        prep_secs: 3,
+        game_secs: 10,
+        game_mode: ClickLobbyGameMode::Timed,
+        owner_name: None,
+        banned_users: HashSet::default(),
+    };
+    let mut lobby = Lobby::new(LOBBY_ID, OWNER, String::default(), ser_msg(&config));
+    add_member(&mut lobby, OWNER, ClickLobbyMemberType::Player);
+    lobby
+}
+
+fn add_member(lobby: &mut Lobby, member_id: u128, member_type: ClickLobbyMemberType)
+{
+    let member_data = LobbyMemberData {
+        connection: ConnectionType::Native,
+        color: member_type.into(),
+    };
+    assert!(lobby.add_member(member_id, member_data));
+}
+
+/// Syncs the lobby with lobby control, then checks if the lobby can launch.
+fn can_launch(checker: &ClickLobbyChecker, lobby: &Lobby) -> bool
+{
+    checker.control.sync_lobby(lobby);
+    checker.can_launch(lobby)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn launch_requires_ready_players()
+{
+    let checker = make_checker();
+    let mut lobby = make_lobby();
+    add_member(&mut lobby, 11, ClickLobbyMemberType::Player);
+    add_member(&mut lobby, 12, ClickLobbyMemberType::Watcher);
+    assert!(!can_launch(&checker, &lobby));
+
+    // watchers don't need to be ready
+    assert!(checker.control.set_ready(LOBBY_ID, OWNER, true));
+    assert!(!can_launch(&checker, &lobby));
+    assert!(checker.control.set_ready(LOBBY_ID, 11, true));
+    assert!(can_launch(&checker, &lobby));
+
+    assert!(checker.control.set_ready(LOBBY_ID, 11, false));
+    assert!(!can_launch(&checker, &lobby));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn launch_requires_min_players()
+{
+    let checker = make_checker();
+    let lobby = make_lobby();
+    assert!(checker.control.set_ready(LOBBY_ID, OWNER, true));
+    assert!(!can_launch(&checker, &lobby));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn launch_ignores_departed_members()
+{
+    let checker = make_checker();
+    let mut lobby = make_lobby();
+    add_member(&mut lobby, 11, ClickLobbyMemberType::Player);
+    add_member(&mut lobby, 12, ClickLobbyMemberType::Player);
+    checker.control.sync_lobby(&lobby);
+    assert!(checker.control.set_ready(LOBBY_ID, OWNER, true));
+    assert!(checker.control.set_ready(LOBBY_ID, 11, true));
+    assert!(!can_launch(&checker, &lobby));
+
+    // an unready player leaving unblocks the launch
+    assert!(lobby.remove_member(12));
+    assert!(can_launch(&checker, &lobby));
+
+    // players who left can't ready up, and start out not ready if they rejoin
+    assert!(!checker.control.set_ready(LOBBY_ID, 12, true));
+    add_member(&mut lobby, 12, ClickLobbyMemberType::Player);
+    assert!(!can_launch(&checker, &lobby));
+    assert!(!checker.control.is_ready(LOBBY_ID, 12));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn launch_waits_for_kicked_members()
+{
+    let checker = make_checker();
+    let mut lobby = make_lobby();
+    add_member(&mut lobby, 11, ClickLobbyMemberType::Player);
+    add_member(&mut lobby, 12, ClickLobbyMemberType::Player);
+    checker.control.sync_lobby(&lobby);
+    for member_id in [OWNER, 11, 12] {
+        assert!(checker.control.set_ready(LOBBY_ID, member_id, true));
+    }
+    assert!(can_launch(&checker, &lobby));
+
+    // kicked members can't launch the game before they leave
+    assert!(checker.control.kick_member(LOBBY_ID, 12));
+    assert!(!checker.control.is_ready(LOBBY_ID, 12));
+    assert!(!can_launch(&checker, &lobby));
+
+    assert!(lobby.remove_member(12));
+    assert!(can_launch(&checker, &lobby));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
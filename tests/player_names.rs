@@ -0,0 +1,46 @@
+use game_core::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn player_name_valid()
+{
+    assert_eq!(validate_player_name("abc"), Ok(()));
+    assert_eq!(validate_player_name("Bob_the-2nd"), Ok(()));
+    assert_eq!(validate_player_name("two words"), Ok(()));
+    assert_eq!(validate_player_name("Observer"), Ok(()));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn player_name_length()
+{
+    assert_eq!(validate_player_name("ab"), Err(InvalidPlayerName::TooShort));
+    assert_eq!(validate_player_name(&"a".repeat(MAX_PLAYER_NAME_CHARS)), Ok(()));
+    assert_eq!(validate_player_name(&"a".repeat(MAX_PLAYER_NAME_CHARS + 1)), Err(InvalidPlayerName::TooLong));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn player_name_bad_characters()
+{
+    assert_eq!(validate_player_name("bob!"), Err(InvalidPlayerName::BadCharacters));
+    assert_eq!(validate_player_name("bøb"), Err(InvalidPlayerName::BadCharacters));
+    assert_eq!(validate_player_name(" bob"), Err(InvalidPlayerName::BadCharacters));
+    assert_eq!(validate_player_name("bob "), Err(InvalidPlayerName::BadCharacters));
+    assert_eq!(validate_player_name("bob  smith"), Err(InvalidPlayerName::BadCharacters));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn player_name_blocked()
+{
+    assert_eq!(validate_player_name("Admin"), Err(InvalidPlayerName::Blocked));
+    assert_eq!(validate_player_name("the admin"), Err(InvalidPlayerName::Blocked));
+    assert_eq!(validate_player_name("Ad_Min"), Err(InvalidPlayerName::Blocked));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,131 @@
+use std::collections::HashMap;
+
+use bevy_girk_game_fw::Tick;
+use game_core::*;
+use wiring_backend::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Makes a player report. Only the fields used for ratings matter.
+fn make_player(client_id: ClientId, team: Option<u8>, rank: u32) -> ClickPlayerReport
+{
+    ClickPlayerReport {
+        client_id,
+        name: default_player_name(client_id),
+        score: PlayerScore::default(),
+        team,
+        connection: PlayerConnection::Connected,
+        rank,
+        click_timeline: Vec::default(),
+        peak_clicks_per_sec: 0,
+        average_clicks_per_sec: 0.0,
+        flagged: false,
+    }
+}
+
+fn make_report(player_reports: Vec<ClickPlayerReport>, team_reports: Vec<ClickTeamReport>) -> ClickGameOverReport
+{
+    ClickGameOverReport {
+        final_game_tick: Tick(0),
+        play_ticks: Tick(0),
+        watcher_count: 0,
+        overtime_winner: None,
+        player_reports,
+        team_reports,
+    }
+}
+
+/// Maps client ids to user ids `100 + client id`.
+fn user_ids(client_ids: &[ClientId]) -> HashMap<ClientId, u128>
+{
+    client_ids
+        .iter()
+        .map(|client_id| (*client_id, 100 + *client_id as u128))
+        .collect()
+}
+
+fn assert_rating(ratings: &PlayerRatings, user_id: u128, rating: f32, games: u32)
+{
+    let actual = ratings.get(user_id);
+    assert!((actual.rating - rating).abs() < 0.001, "user {user_id}: {} != {rating}", actual.rating);
+    assert_eq!(actual.games, games, "user {user_id}");
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn ratings_winner_gains_what_loser_loses()
+{
+    let mut ratings = PlayerRatings::default();
+    let report = make_report(vec![make_player(0, None, 1), make_player(1, None, 2)], vec![]);
+    ratings.apply_game(&report, &user_ids(&[0, 1]));
+
+    // equal ratings expect a draw, so the full half of the K factor changes hands
+    assert_rating(&ratings, 100, DEFAULT_PLAYER_RATING + 16.0, 1);
+    assert_rating(&ratings, 101, DEFAULT_PLAYER_RATING - 16.0, 1);
+
+    // the favorite gains less from a second win
+    ratings.apply_game(&report, &user_ids(&[0, 1]));
+    let winner = ratings.get(100);
+    assert!(winner.rating - (DEFAULT_PLAYER_RATING + 16.0) < 16.0);
+    assert_eq!(winner.games, 2);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn ratings_tie_is_a_draw()
+{
+    let mut ratings = PlayerRatings::default();
+    let report = make_report(vec![make_player(0, None, 1), make_player(1, None, 1)], vec![]);
+    ratings.apply_game(&report, &user_ids(&[0, 1]));
+
+    assert_rating(&ratings, 100, DEFAULT_PLAYER_RATING, 1);
+    assert_rating(&ratings, 101, DEFAULT_PLAYER_RATING, 1);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn ratings_skip_unrated_players()
+{
+    let mut ratings = PlayerRatings::default();
+    let mut flagged = make_player(2, None, 1);
+    flagged.flagged = true;
+    let report = make_report(vec![make_player(0, None, 2), make_player(1, None, 3), flagged], vec![]);
+
+    // client 1 is a bot
+    ratings.apply_game(&report, &user_ids(&[0, 2]));
+
+    // client 0 has no rated opponents
+    assert_rating(&ratings, 100, DEFAULT_PLAYER_RATING, 0);
+    assert_rating(&ratings, 101, DEFAULT_PLAYER_RATING, 0);
+    assert_rating(&ratings, 102, DEFAULT_PLAYER_RATING, 0);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn ratings_use_team_ranks()
+{
+    let mut ratings = PlayerRatings::default();
+    let players = vec![
+        make_player(0, Some(0), 4),
+        make_player(1, Some(0), 1),
+        make_player(2, Some(1), 2),
+        make_player(3, Some(1), 3),
+    ];
+    let teams = vec![
+        ClickTeamReport { team: 0, score: 10, rank: 2 },
+        ClickTeamReport { team: 1, score: 20, rank: 1 },
+    ];
+    ratings.apply_game(&make_report(players, teams), &user_ids(&[0, 1, 2, 3]));
+
+    // teammates aren't rated against each other, and share their team's result
+    assert_rating(&ratings, 100, DEFAULT_PLAYER_RATING - 16.0, 1);
+    assert_rating(&ratings, 101, DEFAULT_PLAYER_RATING - 16.0, 1);
+    assert_rating(&ratings, 102, DEFAULT_PLAYER_RATING + 16.0, 1);
+    assert_rating(&ratings, 103, DEFAULT_PLAYER_RATING + 16.0, 1);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,147 @@
+use std::collections::{HashMap, HashSet};
+use std::net::Ipv6Addr;
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_girk_game_fw::*;
+use bevy_girk_utils::*;
+use bevy_girk_wiring_server::*;
+use game_core::*;
+use renet2_setup::{ClientCounts, GameServerSetupConfig};
+use wiring_game_instance::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+const TICKS_PER_SEC: u32 = 20;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Makes a game app with the game framework but no clients.
+fn make_fw_app() -> App
+{
+    let server_setup_config = GameServerSetupConfig {
+        protocol_id: 0u64,
+        expire_secs: 10u64,
+        timeout_secs: 5i32,
+        server_ip: Ipv6Addr::LOCALHOST.into(),
+        native_port: 0,
+        wasm_wt_port: 0,
+        wasm_ws_port: 0,
+        proxy_ip: None,
+        ws_domain: None,
+        wss_certs: None,
+        native_port_proxy: 0,
+        wasm_ws_port_proxy: 0,
+        wasm_wt_port_proxy: 0,
+        has_wss_proxy: false,
+    };
+    let server_config = GirkServerConfig {
+        clients: GameFwClients::new(HashSet::default()),
+        config: GameFwConfig::new(TICKS_PER_SEC, TICKS_PER_SEC, TICKS_PER_SEC),
+        game_server_config: server_setup_config,
+        resend_time: Duration::from_millis(300),
+        client_counts: ClientCounts::default(),
+    };
+
+    let mut app = App::new();
+    prepare_girk_game_app(&mut app, server_config).expect("failed preparing game app");
+    app
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Makes an initializer for a short game between a player and a bot, with hidden scores.
+fn make_initializer() -> ClickGameInitializer
+{
+    let rules = ClickGameRules {
+        duration_config: GameDurationConfig::new(TICKS_PER_SEC, TICKS_PER_SEC * 3),
+        game_mode: GameMode::Timed,
+        click_limit_config: ClickLimitConfig::new(TICKS_PER_SEC, 15, 20, 0.05),
+        pause_config: PauseConfig::new(PauseMode::Vote, 2, TICKS_PER_SEC * 30),
+        chat_config: ChatConfig::new(200, TICKS_PER_SEC * 10, 5, false),
+        disconnect_config: DisconnectConfig::new(TICKS_PER_SEC * 30),
+    };
+    let game_context = ClickGameContext::new(42u128, TICKS_PER_SEC, rules);
+
+    let make_player = |client_id: ClientId, name: &str| {
+        let player = PlayerState {
+            id: PlayerId { id: client_id },
+            name: PlayerName { name: name.into() },
+            ..Default::default()
+        };
+        (client_id, player)
+    };
+
+    ClickGameInitializer {
+        game_context,
+        players: HashMap::from([make_player(0, "player"), make_player(1, "bot1")]),
+        bots: HashMap::from([(1, BotDifficulty::Medium)]),
+        owner: Some(0),
+        watchers: HashSet::default(),
+        viewers: HashSet::default(),
+        hidden_scores: true,
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Plays a game with scripted player inputs and returns its replay.
+fn record_game() -> ClickGameReplay
+{
+    let mut app = make_fw_app();
+    prepare_game_app_core(&mut app, make_initializer());
+
+    // the player clicks every few ticks during play
+    // - some clicks land in prep and are rejected, so they shouldn't be recorded
+    let inputs = (0..40u32).map(|i| ReplayInput {
+        tick: Tick(TICKS_PER_SEC - 5 + i * 2),
+        client_id: 0,
+        request: ClientRequest::PlayerInput(PlayerInput::ClickButton),
+    });
+    add_replay_inputs(&mut app, inputs);
+
+    for _ in 0..TICKS_PER_SEC * 10 {
+        app.update();
+        if let Some(replay) = app.world().resource::<ReplayRecorder>().replay() {
+            return replay;
+        }
+    }
+    panic!("recorded game did not end");
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn replay_records_full_initializer()
+{
+    let replay = record_game();
+    let initializer = make_initializer();
+
+    assert_eq!(replay.game_context, initializer.game_context);
+    assert!(replay.hidden_scores);
+    assert_eq!(replay.owner, Some(0));
+    assert_eq!(replay.players.len(), 2);
+    assert_eq!(replay.players[1].bot, Some(BotDifficulty::Medium));
+
+    let rerun_initializer = replay.initializer();
+    assert!(rerun_initializer.hidden_scores);
+    assert_eq!(rerun_initializer.bots, initializer.bots);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn replay_rerun_reproduces_report()
+{
+    let replay = record_game();
+    assert!(!replay.inputs.is_empty());
+    assert!(replay.inputs.len() < 40, "clicks outside play should not be recorded");
+    assert!(replay.report.player_reports.iter().all(|report| report.score.score() > 0));
+
+    // re-run from a serialized copy, the same way replay files are loaded
+    let replay = deser_msg::<ClickGameReplay>(&ser_msg(&replay)).expect("failed round-tripping replay");
+    let mut app = make_fw_app();
+    verify_replay(&mut app, &replay, TICKS_PER_SEC).expect("rerun did not reproduce the recorded report");
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,42 @@
+use wiring_backend::*;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn balance_teams_snake_order()
+{
+    let teams: Vec<Option<u8>> = [0, 1, 1, 0, 0, 1].into_iter().map(Some).collect();
+    assert_eq!(balance_teams(6, 2), teams);
+
+    let teams: Vec<Option<u8>> = [0, 1, 2, 2, 1, 0, 0].into_iter().map(Some).collect();
+    assert_eq!(balance_teams(7, 3), teams);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn balance_teams_sizes_differ_by_at_most_one()
+{
+    for num_teams in 1..=4u8 {
+        for num_players in 0..=12 {
+            let mut sizes = vec![0usize; num_teams as usize];
+            for team in balance_teams(num_players, num_teams) {
+                sizes[team.expect("players should have teams") as usize] += 1;
+            }
+            let min = sizes.iter().min().copied().unwrap_or_default();
+            let max = sizes.iter().max().copied().unwrap_or_default();
+            assert!(max - min <= 1, "{num_players} players in {num_teams} teams: {sizes:?}");
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn balance_teams_without_teams()
+{
+    assert_eq!(balance_teams(3, 0), vec![None; 3]);
+    assert!(balance_teams(0, 2).is_empty());
+}
+
+//-------------------------------------------------------------------------------------------------------------------
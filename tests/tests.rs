@@ -1 +1,7 @@
-
+mod click_tracker;
+mod game_over_report;
+mod lobby_checker;
+mod player_names;
+mod player_ratings;
+mod replay;
+mod team_balance;